@@ -0,0 +1,18 @@
+#![feature(extend_one)]
+#![feature(is_sorted)]
+#![feature(trait_alias)]
+
+use cami_benches::shared_criterion;
+use cami_benches::spec::AllocBinaryHeapStr;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+pub fn bench_target(c: &mut Criterion) {
+    shared_criterion::bench_spec::<AllocBinaryHeapStr>(c);
+}
+
+criterion_group! {
+    name = benches;
+    config = shared_criterion::criterion_config();
+    targets = bench_target
+}
+criterion_main!(benches);
@@ -0,0 +1,12 @@
+#![feature(extend_one)]
+#![feature(is_sorted)]
+#![feature(trait_alias)]
+
+use iai_callgrind::main;
+
+cami_benches::iai_map_bench_matrix! {
+    group: bench_group,
+    spec: cami_benches::spec::AllocBTreeMapStr,
+}
+
+main!(library_benchmark_groups = bench_group);
@@ -0,0 +1,10 @@
+#![feature(extend_one)]
+#![feature(is_sorted)]
+#![feature(trait_alias)]
+
+use cami_benches::shared_wall::{self, WallConfig};
+use cami_benches::spec::AllocVecStr;
+
+fn main() {
+    shared_wall::bench_spec_per_env::<AllocVecStr>(&WallConfig::default());
+}
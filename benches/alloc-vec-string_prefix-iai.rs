@@ -0,0 +1,13 @@
+#![feature(extend_one)]
+#![feature(is_sorted)]
+#![feature(trait_alias)]
+
+use iai_callgrind::main;
+
+cami_benches::iai_bench_matrix! {
+    group: bench_group,
+    spec: cami_benches::spec::AllocVecStringPrefix,
+    sorts: [stable, unstable],
+}
+
+main!(library_benchmark_groups = bench_group);
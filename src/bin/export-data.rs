@@ -0,0 +1,101 @@
+//! Export "own" & "out" items of a bench's data (for the seed & configuration per environment
+//! variables, as in benches), and their summary. See README.md.
+#![feature(extend_one)]
+#![feature(is_sorted)]
+#![feature(trait_alias)]
+
+use cami_benches::dataset_stats::DatasetStats;
+use cami_benches::export::{self, DatasetSummary, ExportFormat, ExportItem};
+use cami_benches::spec::{self, BenchSpec, SpecOut};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+const USAGE: &str = "Usage: export-data <bench> [ndjson|csv] [<output file>]
+<bench> is a bench name without its harness suffix, one of:
+  stack-slice-u8, stack-array-u8, alloc-btreeset-u8, alloc-vec-u8, alloc-vec-u8_slice,
+  alloc-vec-str, alloc-vec-string, alloc-vec-str_prefix, alloc-vec-string_prefix,
+  alloc-vec-u8_slice_prefix, alloc-vecdeque-str, alloc-vecdeque_wrapped-str, alloc-binaryheap-str,
+  alloc-btreemap-str, and (with `std` feature) stdlb-hashset-str, stdlb-hashmap-str.
+Items go to <output file> (or to stdout), and their summary to <output file>.summary.txt (and to
+stderr).";
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (bench, format, path) = match &args[..] {
+        [bench] => (bench, ExportFormat::default(), None),
+        [bench, format] => (bench, parse_format(format), None),
+        [bench, format, path] => (bench, parse_format(format), Some(PathBuf::from(path))),
+        _ => exit_with_usage(),
+    };
+    let result = match &bench[..] {
+        "stack-slice-u8" => export_spec::<spec::StackSliceU8>(format, path),
+        "stack-array-u8" => export_spec::<spec::StackArrayU8>(format, path),
+        "alloc-btreeset-u8" => export_spec::<spec::AllocBTreeSetU8>(format, path),
+        "alloc-vec-u8" => export_spec::<spec::AllocVecU8>(format, path),
+        "alloc-vec-u8_slice" => export_spec::<spec::AllocVecU8Slice>(format, path),
+        "alloc-vec-str" => export_spec::<spec::AllocVecStr>(format, path),
+        "alloc-vec-string" => export_spec::<spec::AllocVecString>(format, path),
+        "alloc-vecdeque-str" => export_spec::<spec::AllocVecDequeStr>(format, path),
+        "alloc-vecdeque_wrapped-str" => export_spec::<spec::AllocVecDequeWrappedStr>(format, path),
+        "alloc-binaryheap-str" => export_spec::<spec::AllocBinaryHeapStr>(format, path),
+        "alloc-btreemap-str" => export_spec::<spec::AllocBTreeMapStr>(format, path),
+        "alloc-vec-str_prefix" => export_spec::<spec::AllocVecStrPrefix>(format, path),
+        "alloc-vec-string_prefix" => export_spec::<spec::AllocVecStringPrefix>(format, path),
+        "alloc-vec-u8_slice_prefix" => export_spec::<spec::AllocVecU8SlicePrefix>(format, path),
+        #[cfg(feature = "std")]
+        "stdlb-hashset-str" => export_spec::<spec::StdlbHashSetStr>(format, path),
+        #[cfg(feature = "std")]
+        "stdlb-hashmap-str" => export_spec::<spec::StdlbHashMapStr>(format, path),
+        _ => exit_with_usage(),
+    };
+    if let Err(err) = result {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+fn parse_format(format: &str) -> ExportFormat {
+    ExportFormat::from_str(format).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit_with_usage()
+    })
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{USAGE}");
+    std::process::exit(2)
+}
+
+fn export_spec<Spec: BenchSpec>(format: ExportFormat, path: Option<PathBuf>) -> io::Result<()>
+where
+    Spec::Own: ExportItem,
+    SpecOut<Spec>: ExportItem,
+{
+    let own_and_out = Spec::own_and_out();
+    let summary = format!(
+        "{}\n{}\n\nown:\n{}\nout:\n{}",
+        Spec::group_name_for(&own_and_out),
+        DatasetStats::new(own_and_out.out),
+        DatasetSummary::new(own_and_out.own),
+        DatasetSummary::new(own_and_out.out)
+    );
+    match path {
+        Some(path) => {
+            let mut writer = BufWriter::new(File::create(&path)?);
+            export::write_items(&mut writer, format, &own_and_out)?;
+            writer.flush()?;
+            let mut summary_path = path.into_os_string();
+            summary_path.push(".summary.txt");
+            std::fs::write(summary_path, &summary)?;
+        }
+        None => {
+            let mut writer = BufWriter::new(io::stdout().lock());
+            export::write_items(&mut writer, format, &own_and_out)?;
+            writer.flush()?;
+        }
+    }
+    eprint!("{summary}");
+    Ok(())
+}
@@ -0,0 +1,184 @@
+//! Character classes of generated strings. Apart from [CharClass::AsciiAlphanumeric] and
+//! [CharClass::Ascii], the characters are multi-byte in UTF-8 - so the byte length of a [String]
+//! differs from its length in chars.
+use crate::data::Data;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+use core::str::FromStr;
+
+const ALPHANUMERIC: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Class of characters to generate, uniformly within the class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharClass {
+    /// `0-9`, `A-Z`, `a-z`: 1 byte in UTF-8.
+    AsciiAlphanumeric,
+    /// Any ASCII (including control characters): 1 byte in UTF-8.
+    Ascii,
+    /// Latin-1 Supplement, printable only (U+00A0 to U+00FF): 2 bytes in UTF-8.
+    Latin1,
+    /// CJK Unified Ideographs (U+4E00 to U+9FFF): 3 bytes in UTF-8.
+    Cjk,
+    /// Miscellaneous Symbols and Pictographs, and Emoticons (U+1F300 to U+1F64F): 4 bytes in
+    /// UTF-8.
+    Emoji,
+    /// Each character is from one of [CharClass::PURE] classes, chosen with probability
+    /// proportional to its weight (in the same order as [CharClass::PURE]).
+    Mix { weights: [u32; 5] },
+}
+
+impl CharClass {
+    /// All classes other than [CharClass::Mix].
+    pub const PURE: [CharClass; 5] = [
+        CharClass::AsciiAlphanumeric,
+        CharClass::Ascii,
+        CharClass::Latin1,
+        CharClass::Cjk,
+        CharClass::Emoji,
+    ];
+
+    /// Range of code points (for classes that are one range).
+    const fn code_points(self) -> Option<Range<u32>> {
+        match self {
+            CharClass::Ascii => Some(0..0x80),
+            CharClass::Latin1 => Some(0xA0..0x100),
+            CharClass::Cjk => Some(0x4E00..0xA000),
+            CharClass::Emoji => Some(0x1F300..0x1F650),
+            CharClass::AsciiAlphanumeric | CharClass::Mix { .. } => None,
+        }
+    }
+
+    /// Draw a character of this class.
+    pub fn sample<D: Data + ?Sized>(self, data: &mut D) -> char {
+        match self {
+            CharClass::AsciiAlphanumeric => ALPHANUMERIC[data.usize(0..ALPHANUMERIC.len())] as char,
+            CharClass::Mix { weights } => {
+                let total = weights.iter().map(|&weight| weight as usize).sum::<usize>();
+                assert!(total > 0, "Character class mix needs a positive weight.");
+                let mut pick = data.usize(0..total);
+                for (class, weight) in Self::PURE.into_iter().zip(weights) {
+                    if pick < weight as usize {
+                        return class.sample(data);
+                    }
+                    pick -= weight as usize;
+                }
+                unreachable!()
+            }
+            _ => {
+                let code_points = self.code_points().unwrap();
+                let code_point =
+                    data.usize(code_points.start as usize..code_points.end as usize) as u32;
+                char::from_u32(code_point).unwrap()
+            }
+        }
+    }
+
+    /// Name, as used by [Display] & [FromStr] (other than for [CharClass::Mix]).
+    const fn name(self) -> &'static str {
+        match self {
+            CharClass::AsciiAlphanumeric => "alphanumeric",
+            CharClass::Ascii => "ascii",
+            CharClass::Latin1 => "latin1",
+            CharClass::Cjk => "cjk",
+            CharClass::Emoji => "emoji",
+            CharClass::Mix { .. } => "mix",
+        }
+    }
+}
+
+impl Default for CharClass {
+    /// The same as the (only) class generated before character classes were configurable.
+    fn default() -> Self {
+        CharClass::AsciiAlphanumeric
+    }
+}
+
+/// The same format as [FromStr], so that it can be copied from benchmark IDs to environment
+/// variables.
+impl Display for CharClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CharClass::Mix { weights } => {
+                write!(f, "mix")?;
+                for weight in weights {
+                    write!(f, ":{weight}")?;
+                }
+                Ok(())
+            }
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// Parse `alphanumeric`, `ascii`, `latin1`, `cjk`, `emoji` or
+/// `mix:<alphanumeric>:<ascii>:<latin1>:<cjk>:<emoji>` (with weights as unsigned integers).
+impl FromStr for CharClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split(':');
+        let name = parts.next().unwrap_or_default();
+        if name == "mix" {
+            let weights = parts
+                .map(|weight| {
+                    u32::from_str(weight).map_err(|_| {
+                        format!("Character class weight {weight} should be an unsigned integer.")
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let weights: [u32; 5] = weights.try_into().map_err(|weights: Vec<_>| {
+                format!(
+                    "Character class mix takes 5 weights, but received {}.",
+                    weights.len()
+                )
+            })?;
+            if weights.iter().all(|&weight| weight == 0) {
+                return Err("Character class mix needs a positive weight.".to_owned());
+            }
+            return Ok(CharClass::Mix { weights });
+        }
+        if parts.next().is_some() {
+            return Err(format!("Character class {name} takes no parameters."));
+        }
+        Self::PURE
+            .into_iter()
+            .find(|class| class.name() == name)
+            .ok_or_else(|| format!("Unknown character class {name}. Expecting one of: alphanumeric, ascii, latin1, cjk, emoji, mix:<alphanumeric>:<ascii>:<latin1>:<cjk>:<emoji>."))
+    }
+}
+
+/// Unit of length of string items (and of `MIN_ITEM_LEN` and `MAX_ITEM_LEN`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LenUnit {
+    /// Characters - the byte length (in UTF-8) depends on [CharClass].
+    #[default]
+    Chars,
+    /// Bytes (in UTF-8) - the same as for byte slices, so that `&str` and `&[u8]` items can have
+    /// identical memory footprints.
+    Bytes,
+}
+
+/// The same format as [FromStr].
+impl Display for LenUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LenUnit::Chars => write!(f, "chars"),
+            LenUnit::Bytes => write!(f, "bytes"),
+        }
+    }
+}
+
+/// Parse `chars` or `bytes`.
+impl FromStr for LenUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "chars" => Ok(LenUnit::Chars),
+            "bytes" => Ok(LenUnit::Bytes),
+            other => Err(format!(
+                "Unknown length unit {other}. Expecting one of: chars, bytes."
+            )),
+        }
+    }
+}
@@ -1,6 +1,7 @@
+use crate::data::{Payload, PushPop, RangeQuery};
 use crate::outish::{
     Out, OutCollRetriever, OutCollRetrieverCami, OutCollection, OutCollectionIndicator,
-    OutIndicatorIndicator, OutRetriever,
+    OutIndicatorIndicator, OutMap, OutRetriever,
 };
 use cami::Cami;
 
@@ -51,6 +52,20 @@ pub fn lexi_indicated<
     col
 }
 
+/// Collect, without sorting - for hash-based collections (see [crate::outish::OutHashIndicator]),
+/// which don't sort.
+pub fn hash_indicated<
+    'out,
+    OutType: Out + 'out,
+    OutCollectionHash: OutCollection<'out, OutType>,
+>(
+    out: &'out [OutType],
+) -> OutCollectionHash {
+    let mut col = OutCollectionHash::with_capacity(out.len());
+    col.extend(out.iter().cloned());
+    col
+}
+
 /// Collect [Cami] wrapers around items and sort.
 pub fn cami_stable<
     'out,
@@ -97,3 +112,105 @@ pub fn cami_indicated<
     }
     col
 }
+
+/// Run `workload` on an (initially empty) collection: push (clones of) the indicated items of
+/// `out`, and pop the greatest ones. Return the collection (empty, if the workload pops all
+/// items). The collection must support [OutCollection::pop].
+pub fn push_pop_lexi_indicated<
+    'out,
+    OutType: Out + 'out,
+    OutCollectionLexi: OutCollection<'out, OutType>,
+>(
+    out: &'out [OutType],
+    workload: &[PushPop],
+) -> OutCollectionLexi {
+    let mut col = OutCollectionLexi::with_capacity(out.len());
+    for step in workload {
+        match *step {
+            PushPop::Push(index) => col.extend_one(out[index].clone()),
+            PushPop::Pop => {
+                core::hint::black_box(col.pop());
+            }
+        }
+    }
+    col
+}
+
+/// Like [push_pop_lexi_indicated], but push [Cami] wrappers around the items.
+pub fn push_pop_cami_indicated<
+    'out,
+    OutType: Out + 'out,
+    OutCollectionCami: OutCollection<'out, Cami<OutType>>,
+>(
+    out: &'out [OutType],
+    workload: &[PushPop],
+) -> OutCollectionCami {
+    let mut col = OutCollectionCami::with_capacity(out.len());
+    for step in workload {
+        match *step {
+            PushPop::Push(index) => col.extend_one(Cami::new(out[index].clone())),
+            PushPop::Pop => {
+                core::hint::black_box(col.pop());
+            }
+        }
+    }
+    col
+}
+
+/// Build a map with (clones of) `out` items as keys, and `payloads` (one per item) as values.
+pub fn map_build_lexi_indicated<
+    'out,
+    OutType: Out + 'out,
+    OutMapLexi: OutMap<'out, OutType, Payload>,
+>(
+    out: &'out [OutType],
+    payloads: &[Payload],
+) -> OutMapLexi {
+    let mut map = OutMapLexi::new();
+    map.extend(out.iter().cloned().zip(payloads.iter().copied()));
+    map
+}
+
+/// Like [map_build_lexi_indicated], but the keys are [Cami] wrappers around the items.
+pub fn map_build_cami_indicated<
+    'out,
+    OutType: Out + 'out,
+    OutMapCami: OutMap<'out, Cami<OutType>, Payload>,
+>(
+    out: &'out [OutType],
+    payloads: &[Payload],
+) -> OutMapCami {
+    let mut map = OutMapCami::new();
+    map.extend(
+        out.iter()
+            .cloned()
+            .map(Cami::new)
+            .zip(payloads.iter().copied()),
+    );
+    map
+}
+
+/// Look up each of `keys` (which must all be in `map`).
+pub fn map_lookup<'out, K: Out + 'out, OutMapType: OutMap<'out, K, Payload>>(
+    map: &OutMapType,
+    keys: &[K],
+) {
+    for key in keys {
+        assert!(core::hint::black_box(map.get(key)).is_some());
+    }
+}
+
+/// Run `range_queries` (with [RangeQuery::from] being indexes to `keys`), wrapping around at the
+/// end of `map` (see [RangeQuery]). Return a (wrapping) sum of the first word of visited payloads.
+pub fn map_range<'out, K: Out + 'out, OutMapType: OutMap<'out, K, Payload>>(
+    map: &OutMapType,
+    keys: &[K],
+    range_queries: &[RangeQuery],
+) -> usize {
+    range_queries.iter().fold(0, |sum, query| {
+        map.range_from(&keys[query.from])
+            .chain(map.iter())
+            .take(query.len.min(map.len()))
+            .fold(sum, |sum, (_, payload)| sum.wrapping_add(payload.0[0]))
+    })
+}
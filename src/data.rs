@@ -1,8 +1,14 @@
+use crate::chars::{CharClass, LenUnit};
+use crate::dist::Distribution;
+use crate::fingerprint::{Fingerprint, ItemBytes};
 use crate::outish::Out;
+use crate::prefix::PrefixTree;
 use crate::rnd::{self, Random};
 use alloc::collections::BTreeSet;
 use core::ops::Range;
 use core::str::FromStr;
+use std::path::PathBuf;
+use std::sync::OnceLock;
 
 extern crate alloc;
 
@@ -24,8 +30,12 @@ pub trait OptAsData {
 }
 
 pub trait DataItems: OptAsData {
+    /// Number of items, per [num_items_dist].
     fn num_items(&mut self) -> usize {
-        self.as_data().usize(min_items()..max_items())
+        self.num_items_for_dist(num_items_dist())
+    }
+    fn num_items_for_dist(&mut self, dist: Distribution) -> usize {
+        dist.sample(self.as_data(), min_items()..max_items())
     }
 }
 
@@ -35,31 +45,132 @@ impl<T: Data> OptAsData for T {
     }
 }
 
-/// All default implementations of functions [panic].
+/// Default implementations of [Data::u8], [Data::usize] and [Data::f64] [panic]. The other
+/// functions are built on top of them.
 pub trait Data: DataItems {
     fn u8(&mut self, range: Range<u8>) -> u8 {
         unimplemented!()
     }
+    /// Character per [char_class].
     fn char(&mut self) -> char {
-        unimplemented!()
+        self.char_for_class(char_class())
+    }
+    fn char_for_class(&mut self, class: CharClass) -> char {
+        class.sample(self)
+    }
+    /// Any [u8], including [u8::MAX] (which [Data::u8] can't generate).
+    fn u8_any(&mut self) -> u8 {
+        self.usize(0..u8::MAX as usize + 1) as u8
     }
     fn usize(&mut self, range: Range<usize>) -> usize {
         unimplemented!()
     }
+    /// Uniform in `0.0..1.0`.
+    fn f64(&mut self) -> f64 {
+        unimplemented!()
+    }
+    /// Length per [item_len_dist].
     fn string(&mut self) -> String {
-        self.string_for_len_range(min_item_len()..max_item_len())
+        self.string_for_len_dist(item_len_dist())
+    }
+    fn string_for_len_dist(&mut self, dist: Distribution) -> String {
+        self.string_for_len_range_dist(min_item_len()..max_item_len(), dist)
     }
     /// Param `range` is a range of length of the result [String], however, NOT in bytes, but in
     /// CHARACTERS.
     fn string_for_len_range(&mut self, range: Range<usize>) -> String {
-        let num_chars = self.usize(range);
+        self.string_for_len_range_dist(range, Distribution::Uniform)
+    }
+    /// Like [Data::string_for_len_range], but the length is per `dist`. Characters are per
+    /// [char_class].
+    fn string_for_len_range_dist(&mut self, range: Range<usize>, dist: Distribution) -> String {
+        self.string_for_len_range_dist_class(range, dist, char_class())
+    }
+    /// Length per `dist`, between `MIN_ITEM_LEN` and `MAX_ITEM_LEN`.
+    fn string_for_len_dist_class(&mut self, dist: Distribution, class: CharClass) -> String {
+        self.string_for_len_range_dist_class(min_item_len()..max_item_len(), dist, class)
+    }
+    /// Like [Data::string_for_len_range_dist], but with characters of `class`.
+    fn string_for_len_range_dist_class(
+        &mut self,
+        range: Range<usize>,
+        dist: Distribution,
+        class: CharClass,
+    ) -> String {
+        let num_chars = dist.sample(self, range);
         let mut result = String::with_capacity(4 * num_chars);
         for _ in 0..num_chars {
-            result.push(self.char());
+            result.push(self.char_for_class(class));
         }
         result.shrink_to_fit();
         result
     }
+    /// Exactly `byte_len` bytes long (in UTF-8), with characters of `class`. Once the remaining
+    /// bytes are fewer than the next character needs, they're padded with
+    /// [CharClass::AsciiAlphanumeric] (1 byte each).
+    fn string_for_byte_len(&mut self, byte_len: usize, class: CharClass) -> String {
+        let mut result = String::with_capacity(byte_len);
+        while result.len() < byte_len {
+            let remaining = byte_len - result.len();
+            let ch = self.char_for_class(class);
+            if ch.len_utf8() <= remaining {
+                result.push(ch);
+            } else {
+                while result.len() < byte_len {
+                    result.push(self.char_for_class(CharClass::AsciiAlphanumeric));
+                }
+            }
+        }
+        result
+    }
+    /// Length per `dist`, between `MIN_ITEM_LEN` and `MAX_ITEM_LEN`, in `unit`.
+    fn string_for_len_dist_class_unit(
+        &mut self,
+        dist: Distribution,
+        class: CharClass,
+        unit: LenUnit,
+    ) -> String {
+        let range = min_item_len()..max_item_len();
+        match unit {
+            LenUnit::Chars => self.string_for_len_range_dist_class(range, dist, class),
+            LenUnit::Bytes => self.string_for_byte_len_range_dist_class(range, dist, class),
+        }
+    }
+    /// Like [Data::string_for_len_range_dist_class], but `range` is a range of length in BYTES (in
+    /// UTF-8) - see [Data::string_for_byte_len].
+    fn string_for_byte_len_range_dist_class(
+        &mut self,
+        range: Range<usize>,
+        dist: Distribution,
+        class: CharClass,
+    ) -> String {
+        let byte_len = dist.sample(self, range);
+        self.string_for_byte_len(byte_len, class)
+    }
+    /// Length per [item_len_dist].
+    fn bytes(&mut self) -> Vec<u8> {
+        self.bytes_for_len_dist(item_len_dist())
+    }
+    fn bytes_for_len_dist(&mut self, dist: Distribution) -> Vec<u8> {
+        self.bytes_for_len_range_dist(min_item_len()..max_item_len(), dist)
+    }
+    /// Exactly `len` bytes long.
+    fn bytes_for_len(&mut self, len: usize) -> Vec<u8> {
+        self.bytes_for_len_range_dist(len..len + 1, Distribution::Constant)
+    }
+    /// Param `range` is a range of length of the result [Vec].
+    fn bytes_for_len_range(&mut self, range: Range<usize>) -> Vec<u8> {
+        self.bytes_for_len_range_dist(range, Distribution::Uniform)
+    }
+    /// Like [Data::bytes_for_len_range], but the length is per `dist`.
+    fn bytes_for_len_range_dist(&mut self, range: Range<usize>, dist: Distribution) -> Vec<u8> {
+        let len = dist.sample(self, range);
+        let mut result = Vec::with_capacity(len);
+        for _ in 0..len {
+            result.push(self.u8_any());
+        }
+        result
+    }
 }
 //--------
 
@@ -80,6 +191,10 @@ const MIN_ITEM_LEN: usize = 1;
 const MAX_ITEM_LEN: usize = 1_000;
 
 /// Parse a decimal value from an environment variable. If not present, use `otherwise`.
+///
+/// This and other functions here that read environment variables get called for each generated
+/// item. Hence the public ones read & parse their variable only once (per process), and cache the
+/// result.
 fn from_env_or(env_var_name: &str, otherwise: usize) -> usize {
     let env = std::env::var(env_var_name);
     if let Ok(st) = env {
@@ -97,24 +212,184 @@ const MAX_ITEMS_ENV: &str = "MAX_ITEMS";
 const MIN_ITEM_LEN_ENV: &str = "MIN_ITEM_LEN";
 const MAX_ITEM_LEN_ENV: &str = "MAX_ITEM_LEN";
 pub fn min_items() -> usize {
-    from_env_or(MIN_ITEMS_ENV, MIN_ITEMS)
+    static MIN_ITEMS_CACHE: OnceLock<usize> = OnceLock::new();
+    *MIN_ITEMS_CACHE.get_or_init(|| from_env_or(MIN_ITEMS_ENV, MIN_ITEMS))
 }
 pub fn max_items() -> usize {
-    from_env_or(MAX_ITEMS_ENV, MAX_ITEMS)
+    static MAX_ITEMS_CACHE: OnceLock<usize> = OnceLock::new();
+    *MAX_ITEMS_CACHE.get_or_init(|| from_env_or(MAX_ITEMS_ENV, MAX_ITEMS))
 }
-fn min_item_len() -> usize {
-    from_env_or(MIN_ITEM_LEN_ENV, MIN_ITEM_LEN)
+pub fn min_item_len() -> usize {
+    static MIN_ITEM_LEN_CACHE: OnceLock<usize> = OnceLock::new();
+    *MIN_ITEM_LEN_CACHE.get_or_init(|| from_env_or(MIN_ITEM_LEN_ENV, MIN_ITEM_LEN))
 }
-fn max_item_len() -> usize {
-    from_env_or(MAX_ITEM_LEN_ENV, MAX_ITEM_LEN)
+pub fn max_item_len() -> usize {
+    static MAX_ITEM_LEN_CACHE: OnceLock<usize> = OnceLock::new();
+    *MAX_ITEM_LEN_CACHE.get_or_init(|| from_env_or(MAX_ITEM_LEN_ENV, MAX_ITEM_LEN))
+}
+
+/// Parse a [Distribution] from an environment variable. If not present, use
+/// [Distribution::Uniform].
+fn dist_from_env(env_var_name: &str) -> Distribution {
+    match std::env::var(env_var_name) {
+        Ok(st) => Distribution::from_str(&st)
+            .unwrap_or_else(|err| panic!("Environment variable {env_var_name}: {err}")),
+        Err(_) => Distribution::Uniform,
+    }
+}
+
+const NUM_ITEMS_DIST_ENV: &str = "NUM_ITEMS_DIST";
+const ITEM_LEN_DIST_ENV: &str = "ITEM_LEN_DIST";
+/// Distribution of the number of items (between [min_items] and [max_items]).
+pub fn num_items_dist() -> Distribution {
+    static NUM_ITEMS_DIST_CACHE: OnceLock<Distribution> = OnceLock::new();
+    *NUM_ITEMS_DIST_CACHE.get_or_init(|| dist_from_env(NUM_ITEMS_DIST_ENV))
+}
+/// Distribution of item length (between `MIN_ITEM_LEN` and `MAX_ITEM_LEN`).
+pub fn item_len_dist() -> Distribution {
+    static ITEM_LEN_DIST_CACHE: OnceLock<Distribution> = OnceLock::new();
+    *ITEM_LEN_DIST_CACHE.get_or_init(|| dist_from_env(ITEM_LEN_DIST_ENV))
+}
+
+const CHAR_CLASS_ENV: &str = "CHAR_CLASS";
+/// Class of characters of generated strings, per environment variable `CHAR_CLASS`. If not
+/// present, use [CharClass::default].
+pub fn char_class() -> CharClass {
+    static CHAR_CLASS_CACHE: OnceLock<CharClass> = OnceLock::new();
+    *CHAR_CLASS_CACHE.get_or_init(|| match std::env::var(CHAR_CLASS_ENV) {
+        Ok(st) => CharClass::from_str(&st)
+            .unwrap_or_else(|err| panic!("Environment variable {CHAR_CLASS_ENV}: {err}")),
+        Err(_) => CharClass::default(),
+    })
+}
+
+const ITEM_LEN_UNIT_ENV: &str = "ITEM_LEN_UNIT";
+/// Unit of length of string items, per environment variable `ITEM_LEN_UNIT`. If not present, use
+/// [LenUnit::Chars].
+pub fn item_len_unit() -> LenUnit {
+    static ITEM_LEN_UNIT_CACHE: OnceLock<LenUnit> = OnceLock::new();
+    *ITEM_LEN_UNIT_CACHE.get_or_init(|| match std::env::var(ITEM_LEN_UNIT_ENV) {
+        Ok(st) => LenUnit::from_str(&st)
+            .unwrap_or_else(|err| panic!("Environment variable {ITEM_LEN_UNIT_ENV}: {err}")),
+        Err(_) => LenUnit::default(),
+    })
+}
+
+/// `target` directory of `cargo`: per environment variable `CARGO_TARGET_DIR`, or `target`
+/// (relative to the current directory, which is the package root when run by `cargo bench`).
+pub fn target_dir() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("target"))
+}
+
+const PREFIX_TREE_ENV: &str = "PREFIX_TREE";
+/// Prefix tree for items with shared prefixes, per environment variable `PREFIX_TREE`
+/// (`<depth>:<fan_out>:<prefix_len>`). If not present, use [PrefixTree::default].
+pub fn prefix_tree() -> PrefixTree {
+    static PREFIX_TREE_CACHE: OnceLock<PrefixTree> = OnceLock::new();
+    *PREFIX_TREE_CACHE.get_or_init(|| match std::env::var(PREFIX_TREE_ENV) {
+        Ok(st) => PrefixTree::from_str(&st)
+            .unwrap_or_else(|err| panic!("Environment variable {PREFIX_TREE_ENV}: {err}")),
+        Err(_) => PrefixTree::default(),
+    })
 }
 //--------
 
+/// One step of a priority-queue workload - see [push_pop_workload].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PushPop {
+    /// Push the "out" item at this index.
+    Push(usize),
+    /// Pop the greatest item.
+    Pop,
+}
+
+/// Label of the [Random::fork] child that generates [push_pop_workload].
+pub const PUSH_POP_FORK_LABEL: &str = "push_pop";
+
+/// Push each of `num_items` items (by index, in order). After each push, pop with probability 1/2.
+/// At the end, pop all remaining items. So there are as many pops as pushes, and the queue holds
+/// around `num_items / 2` items at its peak.
+pub fn push_pop_workload<D: Data + ?Sized>(data: &mut D, num_items: usize) -> Vec<PushPop> {
+    let mut workload = Vec::with_capacity(2 * num_items);
+    let mut queue_len = 0;
+    for index in 0..num_items {
+        workload.push(PushPop::Push(index));
+        queue_len += 1;
+        if data.usize(0..2) == 0 {
+            workload.push(PushPop::Pop);
+            queue_len -= 1;
+        }
+    }
+    workload.extend(core::iter::repeat(PushPop::Pop).take(queue_len));
+    workload
+}
+
+/// Value stored in a map (see [crate::outish::OutMap]) under an "out" item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Payload(pub [usize; 2]);
+
+/// Range scan of a map: `len` entries (or all entries, if there are fewer), starting at the key of
+/// "out" item at index `from`, and wrapping around to the first entry at the end of the map. So
+/// the number of entries visited doesn't depend on the ordering of keys (even though the rank of
+/// the key at `from` does).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeQuery {
+    pub from: usize,
+    pub len: usize,
+}
+
+/// Payloads & range queries for map workloads - see [map_workload].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapWorkload {
+    /// One per "out" item.
+    pub payloads: Vec<Payload>,
+    pub range_queries: Vec<RangeQuery>,
+}
+
+/// Label of the [Random::fork] child that generates [map_workload].
+pub const MAP_FORK_LABEL: &str = "map";
+
+/// Max. [RangeQuery::len].
+pub const MAX_RANGE_QUERY_LEN: usize = 32;
+
+/// A [Payload] per each of `num_items` items, and `num_items / 8` (at least one) [RangeQuery]
+/// instances, each of a uniform length `1..=MAX_RANGE_QUERY_LEN`.
+pub fn map_workload<D: Data + ?Sized>(data: &mut D, num_items: usize) -> MapWorkload {
+    let payloads = (0..num_items)
+        .map(|_| Payload([data.usize(0..usize::MAX), data.usize(0..usize::MAX)]))
+        .collect();
+    let range_queries = if num_items == 0 {
+        Vec::new()
+    } else {
+        (0..(num_items / 8).max(1))
+            .map(|_| RangeQuery {
+                from: data.usize(0..num_items),
+                len: data.usize(1..MAX_RANGE_QUERY_LEN + 1),
+            })
+            .collect()
+    };
+    MapWorkload {
+        payloads,
+        range_queries,
+    }
+}
+
 pub fn data_own<OwnType, DataImpl: Data>(
     data: &mut DataImpl,
     generate_own_item: impl Fn(&mut DataImpl) -> OwnType,
 ) -> Vec<OwnType> {
-    let num_items = data.num_items();
+    data_own_for_dist(data, num_items_dist(), generate_own_item)
+}
+
+/// Like [data_own], but the number of items is per `num_items_dist`.
+pub fn data_own_for_dist<OwnType, DataImpl: Data>(
+    data: &mut DataImpl,
+    num_items_dist: Distribution,
+    generate_own_item: impl Fn(&mut DataImpl) -> OwnType,
+) -> Vec<OwnType> {
+    let num_items = data.num_items_for_dist(num_items_dist);
     let mut own_items = Vec::with_capacity(num_items);
 
     for _ in 0..num_items {
@@ -135,17 +410,38 @@ pub struct OwnAndOut<OwnType: 'static, OutType: Out + 'static> {
     pub out: &'static [OutType],
 }
 
+/// Label of the [Random::fork] child that generates items (shared by all benches, so that, for
+/// example, `str` and `string` benches get the same strings).
+pub const ITEMS_FORK_LABEL: &str = "items";
+
 impl<OwnType: 'static, OutType: Out + 'static> OwnAndOut<OwnType, OutType> {
+    /// Generate with a [Random::fork] child for [ITEMS_FORK_LABEL].
     pub fn new_for_rnd<Rnd: Random>(
         generate_own_item: impl Fn(&mut Rnd) -> OwnType,
         generate_out_item: impl Fn(&'static OwnType) -> OutType,
         allows_multiple_equal_items: bool,
     ) -> Self {
-        Self::new_for_data(
-            &mut Rnd::with_seed(),
+        Self::new_for_rnd_dist(
+            generate_own_item,
+            generate_out_item,
+            allows_multiple_equal_items,
+            num_items_dist(),
+        )
+    }
+
+    /// Like [OwnAndOut::new_for_rnd], but the number of items is per `num_items_dist`.
+    pub fn new_for_rnd_dist<Rnd: Random>(
+        generate_own_item: impl Fn(&mut Rnd) -> OwnType,
+        generate_out_item: impl Fn(&'static OwnType) -> OutType,
+        allows_multiple_equal_items: bool,
+        num_items_dist: Distribution,
+    ) -> Self {
+        Self::new_for_data_dist(
+            &mut Rnd::fork(ITEMS_FORK_LABEL),
             generate_own_item,
             generate_out_item,
             allows_multiple_equal_items,
+            num_items_dist,
         )
     }
 
@@ -155,8 +451,43 @@ impl<OwnType: 'static, OutType: Out + 'static> OwnAndOut<OwnType, OutType> {
         generate_out_item: impl Fn(&'static OwnType) -> OutType,
         allows_multiple_equal_items: bool,
     ) -> Self {
-        let own = data_own(data, generate_own_item).leak();
+        Self::new_for_data_dist(
+            data,
+            generate_own_item,
+            generate_out_item,
+            allows_multiple_equal_items,
+            num_items_dist(),
+        )
+    }
+
+    /// Like [OwnAndOut::new_for_data], but the number of items is per `num_items_dist`.
+    pub fn new_for_data_dist<DataImpl: Data>(
+        data: &mut DataImpl,
+        generate_own_item: impl Fn(&mut DataImpl) -> OwnType,
+        generate_out_item: impl Fn(&'static OwnType) -> OutType,
+        allows_multiple_equal_items: bool,
+        num_items_dist: Distribution,
+    ) -> Self {
+        let own = data_own_for_dist(data, num_items_dist, generate_own_item);
+        Self::new_for_own(own, generate_out_item, allows_multiple_equal_items)
+    }
 
+    /// Generate "out" items from already generated (or loaded) `own` items.
+    pub fn new_for_own(
+        own: Vec<OwnType>,
+        generate_out_item: impl Fn(&'static OwnType) -> OutType,
+        allows_multiple_equal_items: bool,
+    ) -> Self {
+        Self::new_for_own_slice(own.leak(), generate_out_item, allows_multiple_equal_items)
+    }
+
+    /// Like [OwnAndOut::new_for_own], but `own` is already static (for example, a part of `own` of
+    /// another instance).
+    pub fn new_for_own_slice(
+        own: &'static [OwnType],
+        generate_out_item: impl Fn(&'static OwnType) -> OutType,
+        allows_multiple_equal_items: bool,
+    ) -> Self {
         let mut out: Vec<OutType> = Vec::<OutType>::with_capacity(own.len());
         out.extend(own.iter().map(generate_out_item));
 
@@ -171,3 +502,9 @@ impl<OwnType: 'static, OutType: Out + 'static> OwnAndOut<OwnType, OutType> {
         Self { own, out }
     }
 }
+
+impl<OwnType: ItemBytes + 'static, OutType: Out + ItemBytes + 'static> OwnAndOut<OwnType, OutType> {
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::new(self.own, self.out)
+    }
+}
@@ -0,0 +1,99 @@
+//! Statistics of "out" items that affect how much [cami::Cami] ordering can gain: spread of
+//! lengths, and depth of shared prefixes.
+use crate::fingerprint::ItemBytes;
+use crate::outish::Out;
+use alloc::collections::BTreeMap;
+use core::fmt::{self, Display, Formatter};
+
+extern crate alloc;
+
+/// Statistics of items, per their [ItemBytes]. Lengths are in bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DatasetStats {
+    pub items: usize,
+    /// Zero if there are no items.
+    pub min_len: usize,
+    pub mean_len: f64,
+    pub max_len: usize,
+    pub distinct_lens: usize,
+    /// Mean length of the longest common prefix (LCP) of each item and its neighbour, once the
+    /// items are sorted lexicographically.
+    pub mean_lcp: f64,
+    /// Fraction of comparisons (of all pairs of items) that are decided by length alone (the
+    /// lengths differ).
+    pub len_decidable: f64,
+}
+
+impl DatasetStats {
+    pub fn new<T: Out + ItemBytes>(items: &[T]) -> Self {
+        let mut sorted = items
+            .iter()
+            .map(|item| item.item_bytes())
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        let mut len_counts = BTreeMap::<usize, usize>::new();
+        for item in &sorted {
+            *len_counts.entry(item.len()).or_insert(0) += 1;
+        }
+        let total_len = sorted.iter().map(|item| item.len()).sum::<usize>();
+        let len_decidable = if sorted.len() < 2 {
+            0.0
+        } else {
+            let pairs = sorted.len() * (sorted.len() - 1);
+            let same_len_pairs = len_counts
+                .values()
+                .map(|&count| count * (count - 1))
+                .sum::<usize>();
+            1.0 - same_len_pairs as f64 / pairs as f64
+        };
+        Self {
+            items: items.len(),
+            min_len: len_counts.keys().next().copied().unwrap_or(0),
+            mean_len: if items.is_empty() {
+                0.0
+            } else {
+                total_len as f64 / items.len() as f64
+            },
+            max_len: len_counts.keys().next_back().copied().unwrap_or(0),
+            distinct_lens: len_counts.len(),
+            mean_lcp: mean_neighbour_lcp(&sorted),
+            len_decidable,
+        }
+    }
+}
+
+/// Mean length of the common prefix of neighbours in `sorted` (which is sorted lexicographically).
+/// Equal neighbours count with their whole length. Zero if there are fewer than 2 items.
+pub(crate) fn mean_neighbour_lcp(sorted: &[&[u8]]) -> f64 {
+    if sorted.len() < 2 {
+        return 0.0;
+    }
+    let total = sorted
+        .windows(2)
+        .map(|pair| {
+            pair[0]
+                .iter()
+                .zip(pair[1])
+                .take_while(|(left, right)| left == right)
+                .count()
+        })
+        .sum::<usize>();
+    total as f64 / (sorted.len() - 1) as f64
+}
+
+/// One line, like `len:1..=20 mean_len:10.500 lens:20 lcp:0.125 len_decidable:0.950`.
+impl Display for DatasetStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "len:{}..={} mean_len:{:.3} lens:{} lcp:{:.3} len_decidable:{:.3}",
+            self.min_len,
+            self.max_len,
+            self.mean_len,
+            self.distinct_lens,
+            self.mean_lcp,
+            self.len_decidable
+        )
+    }
+}
@@ -0,0 +1,142 @@
+//! Distributions of item count and of item length. Cami's length-first ordering is sensitive to the
+//! distribution of lengths - real workloads have skewed lengths (most items short, with a long
+//! tail).
+use crate::data::Data;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+use core::str::FromStr;
+
+/// Distribution of a value within a [Range] of [usize] (for example, a range of item lengths).
+///
+/// Apart from [Distribution::Uniform], the parameters are relative to `range.start`. Values beyond
+/// the range get clamped to its last value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    /// Uniform, as per [Data::usize].
+    Uniform,
+    /// Always `range.start`.
+    Constant,
+    /// Bounded power law (a continuous approximation of Zipf's law): rank `k` (where `k` is one
+    /// for `range.start`) has probability proportional to `k^-exponent`.
+    Zipf { exponent: f64 },
+    /// Natural logarithm of `1 + value - range.start` is normally distributed, with mean `mu` and
+    /// standard deviation `sigma`.
+    LogNormal { mu: f64, sigma: f64 },
+    /// `value - range.start` is exponentially distributed with mean `mean`.
+    Exponential { mean: f64 },
+    /// With probability `low_weight` uniform within the lowest tenth of the range, otherwise
+    /// uniform within the highest tenth.
+    Bimodal { low_weight: f64 },
+}
+
+impl Distribution {
+    /// Draw a value within `range`, which must not be empty.
+    pub fn sample<D: Data + ?Sized>(self, data: &mut D, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "Empty range {range:?}.");
+        let len = range.end - range.start;
+        let offset = match self {
+            Distribution::Uniform => return data.usize(range),
+            Distribution::Constant => 0,
+            Distribution::Zipf { exponent } => {
+                let n = len as f64;
+                let u = data.f64();
+                let rank = if (exponent - 1.0).abs() < 1e-9 {
+                    (n + 1.0).powf(u)
+                } else {
+                    let one_minus_s = 1.0 - exponent;
+                    (((n + 1.0).powf(one_minus_s) - 1.0) * u + 1.0).powf(1.0 / one_minus_s)
+                };
+                (rank.floor() as usize).max(1) - 1
+            }
+            Distribution::LogNormal { mu, sigma } => {
+                let value = (mu + sigma * standard_normal(data)).exp() - 1.0;
+                value.max(0.0) as usize
+            }
+            Distribution::Exponential { mean } => (-mean * (1.0 - data.f64()).ln()) as usize,
+            Distribution::Bimodal { low_weight } => {
+                let mode_len = (len / 10).max(1);
+                if data.f64() < low_weight {
+                    data.usize(0..mode_len)
+                } else {
+                    data.usize(len - mode_len..len)
+                }
+            }
+        };
+        range.start + offset.min(len - 1)
+    }
+}
+
+/// Box-Muller transform.
+fn standard_normal<D: Data + ?Sized>(data: &mut D) -> f64 {
+    // 1 - f64() is in (0, 1], so that its logarithm is finite.
+    let u1 = 1.0 - data.f64();
+    let u2 = data.f64();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos()
+}
+
+impl Default for Distribution {
+    fn default() -> Self {
+        Distribution::Uniform
+    }
+}
+
+/// The same format as [FromStr], so that it can be copied from benchmark IDs to environment
+/// variables.
+impl Display for Distribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Distribution::Uniform => write!(f, "uniform"),
+            Distribution::Constant => write!(f, "constant"),
+            Distribution::Zipf { exponent } => write!(f, "zipf:{exponent}"),
+            Distribution::LogNormal { mu, sigma } => write!(f, "lognormal:{mu}:{sigma}"),
+            Distribution::Exponential { mean } => write!(f, "exponential:{mean}"),
+            Distribution::Bimodal { low_weight } => write!(f, "bimodal:{low_weight}"),
+        }
+    }
+}
+
+/// Parse `uniform`, `constant`, `zipf:<exponent>`, `lognormal:<mu>:<sigma>`,
+/// `exponential:<mean>` or `bimodal:<low_weight>`.
+impl FromStr for Distribution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split(':');
+        let name = parts.next().unwrap_or_default();
+        let params = parts
+            .map(|param| {
+                f64::from_str(param)
+                    .map_err(|_| format!("Distribution parameter {param} should be a number."))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let expected_params = match name {
+            "uniform" | "constant" => 0,
+            "zipf" | "exponential" | "bimodal" => 1,
+            "lognormal" => 2,
+            _ => return Err(format!("Unknown distribution {name}. Expecting one of: uniform, constant, zipf:<exponent>, lognormal:<mu>:<sigma>, exponential:<mean>, bimodal:<low_weight>.")),
+        };
+        if params.len() != expected_params {
+            return Err(format!(
+                "Distribution {name} takes {expected_params} parameter(s), but received {}.",
+                params.len()
+            ));
+        }
+        Ok(match name {
+            "uniform" => Distribution::Uniform,
+            "constant" => Distribution::Constant,
+            "zipf" => Distribution::Zipf {
+                exponent: params[0],
+            },
+            "exponential" => Distribution::Exponential { mean: params[0] },
+            "bimodal" => Distribution::Bimodal {
+                low_weight: params[0],
+            },
+            "lognormal" => Distribution::LogNormal {
+                mu: params[0],
+                sigma: params[1],
+            },
+            _ => unreachable!(),
+        })
+    }
+}
@@ -0,0 +1,222 @@
+//! Export of generated "own" & "out" items (see [crate::data::OwnAndOut]) to NDJSON or CSV, with
+//! a [DatasetSummary] - so that we can see what a benchmark actually ran on. See also binary
+//! `export-data` (src/bin/export-data.rs).
+use crate::data::OwnAndOut;
+use crate::dataset_stats;
+use crate::fingerprint::ItemBytes;
+use crate::outish::Out;
+use alloc::collections::BTreeMap;
+use core::fmt::{self, Display, Formatter, Write as _};
+use core::str::FromStr;
+use std::io::{self, Write};
+
+extern crate alloc;
+
+/// Format of exported items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// One JSON object per line: `{"set":"own","index":0,"len":3,"item":"abc"}`.
+    #[default]
+    Ndjson,
+    /// Header `set,index,len,item`, then one item per line.
+    Csv,
+}
+
+/// The same format as [FromStr].
+impl Display for ExportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Ndjson => write!(f, "ndjson"),
+            ExportFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// Parse `ndjson` or `csv`.
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "ndjson" => Ok(ExportFormat::Ndjson),
+            "csv" => Ok(ExportFormat::Csv),
+            other => Err(format!(
+                "Unknown export format {other}. Expecting one of: ndjson, csv."
+            )),
+        }
+    }
+}
+
+/// How an item is written by [write_items]. By default (per [ItemBytes]): UTF-8 items as (JSON or
+/// CSV) strings, others as a JSON array of bytes, or as `0x` followed by hex digits in CSV.
+pub trait ExportItem: ItemBytes {
+    fn write_json(&self, result: &mut String) {
+        match core::str::from_utf8(self.item_bytes()) {
+            Ok(string) => write_json_string(string, result),
+            Err(_) => {
+                result.push('[');
+                for (index, byte) in self.item_bytes().iter().enumerate() {
+                    if index > 0 {
+                        result.push(',');
+                    }
+                    write!(result, "{byte}").unwrap();
+                }
+                result.push(']');
+            }
+        }
+    }
+    fn write_csv(&self, result: &mut String) {
+        match core::str::from_utf8(self.item_bytes()) {
+            Ok(string) => {
+                result.push('"');
+                result.push_str(&string.replace('"', "\"\""));
+                result.push('"');
+            }
+            Err(_) => {
+                result.push_str("0x");
+                for byte in self.item_bytes() {
+                    write!(result, "{byte:02x}").unwrap();
+                }
+            }
+        }
+    }
+}
+
+/// As a number.
+impl ExportItem for u8 {
+    fn write_json(&self, result: &mut String) {
+        write!(result, "{self}").unwrap();
+    }
+    fn write_csv(&self, result: &mut String) {
+        write!(result, "{self}").unwrap();
+    }
+}
+impl ExportItem for [u8] {}
+impl ExportItem for str {}
+impl ExportItem for Vec<u8> {}
+impl ExportItem for String {}
+impl<T: ExportItem + ?Sized> ExportItem for &T {
+    fn write_json(&self, result: &mut String) {
+        (**self).write_json(result)
+    }
+    fn write_csv(&self, result: &mut String) {
+        (**self).write_csv(result)
+    }
+}
+
+fn write_json_string(string: &str, result: &mut String) {
+    result.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+}
+
+/// Write "own" items (as set `own`), then "out" items (as set `out`) to `writer`. (For CSV, write a
+/// header first.)
+pub fn write_items<OwnType: ExportItem, OutType: Out + ExportItem>(
+    writer: &mut impl Write,
+    format: ExportFormat,
+    own_and_out: &OwnAndOut<OwnType, OutType>,
+) -> io::Result<()> {
+    if format == ExportFormat::Csv {
+        writeln!(writer, "set,index,len,item")?;
+    }
+    let mut line = String::new();
+    let mut write_set = |set: &str, items: &mut dyn Iterator<Item = &dyn ExportItem>| {
+        for (index, item) in items.enumerate() {
+            line.clear();
+            let len = item.item_bytes().len();
+            match format {
+                ExportFormat::Ndjson => {
+                    write!(
+                        line,
+                        r#"{{"set":"{set}","index":{index},"len":{len},"item":"#
+                    )
+                    .unwrap();
+                    item.write_json(&mut line);
+                    line.push('}');
+                }
+                ExportFormat::Csv => {
+                    write!(line, "{set},{index},{len},").unwrap();
+                    item.write_csv(&mut line);
+                }
+            }
+            writeln!(writer, "{line}")?;
+        }
+        Ok::<(), io::Error>(())
+    };
+    write_set(
+        "own",
+        &mut own_and_out.own.iter().map(|item| item as &dyn ExportItem),
+    )?;
+    write_set(
+        "out",
+        &mut own_and_out.out.iter().map(|item| item as &dyn ExportItem),
+    )
+}
+
+/// Summary of items (their [ItemBytes]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatasetSummary {
+    pub items: usize,
+    pub distinct_items: usize,
+    /// Length (in bytes) => number of items of that length.
+    pub len_histogram: BTreeMap<usize, usize>,
+    /// Average length (in bytes) of the common prefix of neighbours, once the items are sorted
+    /// lexicographically (by bytes). Equal neighbours count with their whole length. Zero if there
+    /// are fewer than 2 items.
+    pub avg_common_prefix_len: f64,
+}
+
+impl DatasetSummary {
+    pub fn new<T: ItemBytes>(items: &[T]) -> Self {
+        let mut sorted = items
+            .iter()
+            .map(|item| item.item_bytes())
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        let mut len_histogram = BTreeMap::new();
+        for item in &sorted {
+            *len_histogram.entry(item.len()).or_insert(0) += 1;
+        }
+        let distinct_items = if sorted.is_empty() {
+            0
+        } else {
+            1 + sorted.windows(2).filter(|pair| pair[0] != pair[1]).count()
+        };
+        Self {
+            items: items.len(),
+            distinct_items,
+            len_histogram,
+            avg_common_prefix_len: dataset_stats::mean_neighbour_lcp(&sorted),
+        }
+    }
+}
+
+/// Human-readable, multi-line. The histogram has one `<len>: <count>` line per length.
+impl Display for DatasetSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "items: {}", self.items)?;
+        writeln!(f, "distinct items: {}", self.distinct_items)?;
+        writeln!(
+            f,
+            "avg common prefix len (sorted neighbours): {:.3}",
+            self.avg_common_prefix_len
+        )?;
+        writeln!(f, "len histogram (bytes: items):")?;
+        for (len, count) in &self.len_histogram {
+            writeln!(f, "  {len}: {count}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+//! [Data] read from local files, so that Cami can be benchmarked on real-world items (log keys,
+//! file paths, identifiers...) through [crate::data::OwnAndOut::new_for_data].
+use crate::chars::CharClass;
+use crate::data::{Data, DataItems};
+use crate::dist::Distribution;
+use alloc::vec::IntoIter;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+use core::str::FromStr;
+use std::path::Path;
+
+extern crate alloc;
+
+/// Format of an items file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileFormat {
+    /// One item per line (`\n` or `\r\n`). A trailing newline doesn't make an extra (empty) item.
+    Lines,
+    /// One JSON array of strings (or one JSON string) per line. Blank lines are skipped.
+    NdjsonStrings,
+    /// Each item is its length (little-endian `u64`), followed by its bytes.
+    LengthPrefixed,
+}
+
+impl FileFormat {
+    /// Per extension of `path`: `.ndjson` or `.jsonl` means [FileFormat::NdjsonStrings], `.bin`
+    /// means [FileFormat::LengthPrefixed], anything else [FileFormat::Lines].
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ndjson" | "jsonl") => FileFormat::NdjsonStrings,
+            Some("bin") => FileFormat::LengthPrefixed,
+            _ => FileFormat::Lines,
+        }
+    }
+
+    /// Split `bytes` (contents of a file) into items.
+    pub fn parse(self, bytes: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+        match self {
+            FileFormat::Lines => {
+                let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+                if bytes.is_empty() {
+                    return Ok(Vec::new());
+                }
+                Ok(bytes
+                    .split(|&byte| byte == b'\n')
+                    .map(|line| line.strip_suffix(b"\r").unwrap_or(line).to_vec())
+                    .collect())
+            }
+            FileFormat::NdjsonStrings => {
+                let text = core::str::from_utf8(bytes).map_err(|err| format!("{err}"))?;
+                let mut items = Vec::new();
+                for (index, line) in text.lines().enumerate() {
+                    if !line.trim().is_empty() {
+                        json::parse_line(line, &mut items)
+                            .map_err(|err| format!("Line {}: {err}", index + 1))?;
+                    }
+                }
+                Ok(items)
+            }
+            FileFormat::LengthPrefixed => {
+                let mut items = Vec::new();
+                let mut rest = bytes;
+                while !rest.is_empty() {
+                    if rest.len() < 8 {
+                        return Err("Truncated item length.".to_owned());
+                    }
+                    let (len, after_len) = rest.split_at(8);
+                    let len = u64::from_le_bytes(len.try_into().unwrap());
+                    let len = usize::try_from(len)
+                        .ok()
+                        .filter(|&len| len <= after_len.len())
+                        .ok_or_else(|| format!("Item length {len} is beyond the end of file."))?;
+                    let (item, after_item) = after_len.split_at(len);
+                    items.push(item.to_vec());
+                    rest = after_item;
+                }
+                Ok(items)
+            }
+        }
+    }
+}
+
+/// The same format as [FromStr].
+impl Display for FileFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FileFormat::Lines => write!(f, "lines"),
+            FileFormat::NdjsonStrings => write!(f, "ndjson"),
+            FileFormat::LengthPrefixed => write!(f, "length-prefixed"),
+        }
+    }
+}
+
+/// Parse `lines`, `ndjson` or `length-prefixed`.
+impl FromStr for FileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "lines" => Ok(FileFormat::Lines),
+            "ndjson" => Ok(FileFormat::NdjsonStrings),
+            "length-prefixed" => Ok(FileFormat::LengthPrefixed),
+            other => Err(format!(
+                "Unknown file format {other}. Expecting one of: lines, ndjson, length-prefixed."
+            )),
+        }
+    }
+}
+
+/// Items read from a file, in the file's order. Like [crate::testish::DataTest], the number of
+/// items is given (by the file), and each call to [Data::string] or [Data::bytes] (or to any of
+/// their variants, ignoring any length, distribution or character class) returns the next item.
+///
+/// There is no randomness: [Data::u8], [Data::usize], [Data::f64] (and hence [Data::char]) [panic].
+pub struct DataFile(IntoIter<Vec<u8>>);
+
+impl DataFile {
+    pub fn new(items: Vec<Vec<u8>>) -> Self {
+        Self(items.into_iter())
+    }
+
+    /// Read & parse `path` as per `format`.
+    pub fn open(path: &Path, format: FileFormat) -> Result<Self, String> {
+        let bytes = std::fs::read(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let items = format
+            .parse(&bytes)
+            .map_err(|err| format!("{} ({format}): {err}", path.display()))?;
+        Ok(Self::new(items))
+    }
+
+    /// Like [DataFile::open], with [FileFormat::from_path].
+    pub fn open_detect(path: &Path) -> Result<Self, String> {
+        Self::open(path, FileFormat::from_path(path))
+    }
+
+    fn next_item(&mut self) -> Vec<u8> {
+        self.0.next().expect("No more items in the file.")
+    }
+
+    fn next_string(&mut self) -> String {
+        String::from_utf8(self.next_item())
+            .unwrap_or_else(|err| panic!("An item is not valid UTF-8: {err}"))
+    }
+}
+
+impl DataItems for DataFile {
+    /// Ignore `dist`: the number of items is given by the file.
+    fn num_items_for_dist(&mut self, _dist: Distribution) -> usize {
+        self.0.len()
+    }
+}
+
+impl Data for DataFile {
+    fn string_for_len_range_dist_class(
+        &mut self,
+        _range: Range<usize>,
+        _dist: Distribution,
+        _class: CharClass,
+    ) -> String {
+        self.next_string()
+    }
+    fn string_for_byte_len(&mut self, _byte_len: usize, _class: CharClass) -> String {
+        self.next_string()
+    }
+    fn string_for_byte_len_range_dist_class(
+        &mut self,
+        _range: Range<usize>,
+        _dist: Distribution,
+        _class: CharClass,
+    ) -> String {
+        self.next_string()
+    }
+    fn bytes_for_len_range_dist(&mut self, _range: Range<usize>, _dist: Distribution) -> Vec<u8> {
+        self.next_item()
+    }
+}
+
+/// Minimal JSON parsing: just (arrays of) strings.
+mod json {
+    use core::iter::Peekable;
+    use core::str::Chars;
+
+    /// Parse a JSON array of strings (or one JSON string), and push the strings (as bytes) to
+    /// `items`.
+    pub fn parse_line(line: &str, items: &mut Vec<Vec<u8>>) -> Result<(), String> {
+        let mut chars = line.chars().peekable();
+        skip_whitespace(&mut chars);
+        match chars.peek() {
+            Some('"') => items.push(parse_string(&mut chars)?.into_bytes()),
+            Some('[') => {
+                chars.next();
+                skip_whitespace(&mut chars);
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                } else {
+                    loop {
+                        skip_whitespace(&mut chars);
+                        items.push(parse_string(&mut chars)?.into_bytes());
+                        skip_whitespace(&mut chars);
+                        match chars.next() {
+                            Some(',') => {}
+                            Some(']') => break,
+                            other => return Err(format!("Expecting , or ] but found {other:?}.")),
+                        }
+                    }
+                }
+            }
+            other => return Err(format!("Expecting [ or \" but found {other:?}.")),
+        }
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            None => Ok(()),
+            Some(c) => Err(format!("Unexpected {c:?} after the value.")),
+        }
+    }
+
+    fn skip_whitespace(chars: &mut Peekable<Chars>) {
+        while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+    }
+
+    fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+        if chars.next() != Some('"') {
+            return Err("Expecting a string.".to_owned());
+        }
+        let mut result = String::new();
+        loop {
+            match chars.next().ok_or("Unterminated string.")? {
+                '"' => return Ok(result),
+                '\\' => match chars.next().ok_or("Unterminated escape.")? {
+                    '"' => result.push('"'),
+                    '\\' => result.push('\\'),
+                    '/' => result.push('/'),
+                    'b' => result.push('\u{8}'),
+                    'f' => result.push('\u{c}'),
+                    'n' => result.push('\n'),
+                    'r' => result.push('\r'),
+                    't' => result.push('\t'),
+                    'u' => {
+                        let high = parse_hex4(chars)?;
+                        let code_point = if (0xD800..0xDC00).contains(&high) {
+                            if chars.next() != Some('\\') || chars.next() != Some('u') {
+                                return Err("Expecting a low surrogate.".to_owned());
+                            }
+                            let low = parse_hex4(chars)?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return Err(format!("Invalid low surrogate {low:04x}."));
+                            }
+                            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                        } else {
+                            high
+                        };
+                        result.push(
+                            char::from_u32(code_point)
+                                .ok_or_else(|| format!("Invalid code point {code_point:x}."))?,
+                        );
+                    }
+                    other => return Err(format!("Invalid escape \\{other}.")),
+                },
+                c => result.push(c),
+            }
+        }
+    }
+
+    fn parse_hex4(chars: &mut Peekable<Chars>) -> Result<u32, String> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = chars
+                .next()
+                .and_then(|c| c.to_digit(16))
+                .ok_or("Invalid \\u escape.")?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+}
@@ -0,0 +1,101 @@
+//! Fingerprint of a generated dataset, so that results from different machines (or runs) can be
+//! matched to the data they were measured on.
+use core::fmt::{self, Display, Formatter};
+
+/// Version of data generation. Bump it whenever the same seed & configuration generate different
+/// data (for example, a change in [crate::data::Data] or in [crate::prng]). It's a part of
+/// [Fingerprint::hash].
+pub const GENERATOR_VERSION: u32 = 1;
+
+/// Bytes of an item, for [Fingerprint].
+pub trait ItemBytes {
+    fn item_bytes(&self) -> &[u8];
+}
+
+impl ItemBytes for u8 {
+    fn item_bytes(&self) -> &[u8] {
+        core::slice::from_ref(self)
+    }
+}
+impl ItemBytes for [u8] {
+    fn item_bytes(&self) -> &[u8] {
+        self
+    }
+}
+impl ItemBytes for str {
+    fn item_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+impl ItemBytes for Vec<u8> {
+    fn item_bytes(&self) -> &[u8] {
+        self
+    }
+}
+impl ItemBytes for String {
+    fn item_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+impl<T: ItemBytes + ?Sized> ItemBytes for &T {
+    fn item_bytes(&self) -> &[u8] {
+        (**self).item_bytes()
+    }
+}
+
+/// Stable (across platforms and runs) summary of "own" & "out" items (see
+/// [crate::data::OwnAndOut]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// FNV-1a (64 bit) of [GENERATOR_VERSION], each "own" item (its length and bytes, so that
+    /// item boundaries matter), the number of "out" items and each "out" item (the same way as
+    /// "own" items). All numbers are hashed as little-endian `u64`.
+    pub hash: u64,
+    pub own_items: usize,
+    /// Total length of "own" items, in bytes.
+    pub own_bytes: usize,
+    pub out_items: usize,
+}
+
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+pub(crate) fn fnv_1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Length and bytes of `item`.
+fn fnv_1a_item<T: ItemBytes + ?Sized>(hash: u64, item: &T) -> u64 {
+    let bytes = item.item_bytes();
+    fnv_1a(fnv_1a(hash, &(bytes.len() as u64).to_le_bytes()), bytes)
+}
+
+impl Fingerprint {
+    pub fn new<OwnType: ItemBytes, OutType: ItemBytes>(own: &[OwnType], out: &[OutType]) -> Self {
+        let mut hash = fnv_1a(FNV_OFFSET_BASIS, &(GENERATOR_VERSION as u64).to_le_bytes());
+        let mut own_bytes = 0;
+        for item in own {
+            hash = fnv_1a_item(hash, item);
+            own_bytes += item.item_bytes().len();
+        }
+        hash = fnv_1a(hash, &(out.len() as u64).to_le_bytes());
+        for item in out {
+            hash = fnv_1a_item(hash, item);
+        }
+        Self {
+            hash,
+            own_items: own.len(),
+            own_bytes,
+            out_items: out.len(),
+        }
+    }
+}
+
+/// `fp:<hash>`, as a part of benchmark IDs.
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "fp:{:016x}", self.hash)
+    }
+}
@@ -2,10 +2,20 @@
 #![feature(is_sorted)]
 #![feature(trait_alias)]
 
+pub mod chars;
 pub mod col;
 pub mod data;
+pub mod dataset_stats;
+pub mod dist;
+pub mod export;
+pub mod file_data;
+pub mod fingerprint;
 pub mod outish;
+pub mod prefix;
+pub mod prng;
 pub mod rnd;
+pub mod snapshot;
+pub mod spec;
 pub mod testish;
 
 // Feature-based:
@@ -15,3 +25,6 @@ pub mod shared_criterion;
 
 #[cfg(feature = "iai-callgrind")]
 pub mod shared_iai;
+
+#[cfg(feature = "wall")]
+pub mod shared_wall;
@@ -1,6 +1,8 @@
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
 use cami::{Cami, CamiOrd};
 use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::Bound;
 
 extern crate alloc;
 
@@ -30,6 +32,13 @@ where
     /// If `false`, [OutCollection::sort] may `panic!` (unsupported). Normally `true` in development
     /// with `std` or `alloc`.
     const HAS_SORT: bool;
+    /// Whether this is a priority queue. If `false`, [OutCollection::pop] may `panic!`
+    /// (unsupported).
+    const HAS_POP: bool;
+    /// Whether [OutCollection::binary_search] is a real (logarithmic, or hash-based) lookup. If
+    /// `false`, it's a linear scan (for example, in a priority queue), which is not worth
+    /// benchmarking.
+    const HAS_BINARY_SEARCH: bool;
 
     /// Prefer [OutCollection::with_capacity] if possible.
     fn new() -> Self;
@@ -67,6 +76,10 @@ where
     /// Binary search; return `true` if found an equal item (or if found an equal key, in case of
     /// [alloc::collections::BTreeMap] and friends.)
     fn binary_search(&self, x: &T) -> bool;
+
+    /// Remove and return the greatest item (as a priority queue), or [None] if empty. If
+    /// [OutCollection::HAS_POP] is `false`, this method may `panic!`.
+    fn pop(&mut self) -> Option<T>;
 }
 
 pub trait OutCollectionIndicator {
@@ -103,6 +116,8 @@ where
     const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
     const HAS_SORT_UNSTABLE: bool = true;
     const HAS_SORT: bool = true;
+    const HAS_POP: bool = false;
+    const HAS_BINARY_SEARCH: bool = true;
 
     fn new() -> Self {
         Self(Vec::new(), PhantomData)
@@ -135,6 +150,9 @@ where
     fn binary_search(&self, x: &T) -> bool {
         self.0.binary_search(x).is_ok()
     }
+    fn pop(&mut self) -> Option<T> {
+        unreachable!();
+    }
 }
 
 impl<'own, T> OutCollectionVec<'own, T>
@@ -181,6 +199,8 @@ where
     const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = false;
     const HAS_SORT_UNSTABLE: bool = false;
     const HAS_SORT: bool = true;
+    const HAS_POP: bool = false;
+    const HAS_BINARY_SEARCH: bool = true;
 
     fn new() -> Self {
         Self(BTreeSet::new(), PhantomData)
@@ -212,6 +232,9 @@ where
     fn binary_search(&self, x: &T) -> bool {
         self.0.get(x).is_some()
     }
+    fn pop(&mut self) -> Option<T> {
+        unreachable!();
+    }
 }
 
 pub struct OutCollectionBTreeSetIndicator();
@@ -220,6 +243,321 @@ impl OutCollectionIndicator for OutCollectionBTreeSetIndicator {
 }
 // End of: BTreeSet-based collection
 
+/// `VecDeque`-based collection. Its items are contiguous (not wrapped around the end of the ring
+/// buffer) - unless they get there by [VecDeque::push_front]. For a wrapped-around layout see
+/// [OutCollectionVecDequeWrapped].
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct OutCollectionVecDeque<'own, T>(pub VecDeque<T>, PhantomData<&'own ()>)
+where
+    T: Out + 'own;
+
+impl<'own, T> Extend<T> for OutCollectionVecDeque<'own, T>
+where
+    T: Out + 'own,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+    fn extend_one(&mut self, item: T) {
+        self.0.extend_one(item);
+    }
+    fn extend_reserve(&mut self, additional: usize) {
+        self.0.extend_reserve(additional);
+    }
+}
+impl<'own, T> OutCollection<'own, T> for OutCollectionVecDeque<'own, T>
+where
+    T: Out + 'own,
+{
+    const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
+    const HAS_SORT_UNSTABLE: bool = true;
+    const HAS_SORT: bool = true;
+    const HAS_POP: bool = false;
+    const HAS_BINARY_SEARCH: bool = true;
+
+    fn new() -> Self {
+        Self(VecDeque::new(), PhantomData)
+    }
+    fn with_capacity(capacity: usize) -> Self {
+        Self(VecDeque::with_capacity(capacity), PhantomData)
+    }
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.0.iter()
+    }
+    fn is_sorted(&self) -> bool {
+        let iter = self.0.iter();
+        core::hint::black_box(iter).is_sorted()
+    }
+    fn sort(&mut self) {
+        self.0.make_contiguous().sort();
+    }
+    fn sort_unstable(&mut self) {
+        self.0.make_contiguous().sort_unstable();
+    }
+    fn binary_search(&self, x: &T) -> bool {
+        self.0.binary_search(x).is_ok()
+    }
+    fn pop(&mut self) -> Option<T> {
+        unreachable!();
+    }
+}
+
+pub struct OutCollectionVecDequeIndicator();
+impl OutCollectionIndicator for OutCollectionVecDequeIndicator {
+    type OutCollectionImpl<'own, T> = OutCollectionVecDeque<'own, T> where T: Out + 'own;
+}
+
+/// Like [OutCollectionVecDeque], but its items are wrapped around the end of the ring buffer: the
+/// first half of them is at the end of the buffer, and the rest is at its start. So
+/// [OutCollection::binary_search] crosses the two halves (as [VecDeque::as_slices]).
+///
+/// [Extend::extend] (into an empty collection) lays out the items like that. Sorting uses
+/// [VecDeque::make_contiguous] (as an application would), and then it restores the wrapped-around
+/// layout in place (without allocating) - that is a part of the cost of [OutCollection::sort] &
+/// [OutCollection::sort_unstable].
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct OutCollectionVecDequeWrapped<'own, T>(pub VecDeque<T>, PhantomData<&'own ()>)
+where
+    T: Out + 'own;
+
+impl<'own, T> OutCollectionVecDequeWrapped<'own, T>
+where
+    T: Out + 'own,
+{
+    /// Replace the items with `items` (in the same order), wrapped around.
+    fn wrap_around(&mut self, items: Vec<T>) {
+        // [VecDeque::clear] resets the start of the ring buffer to its beginning, so
+        // [VecDeque::push_front] then goes to the end of the buffer.
+        self.0.clear();
+        self.0.reserve(items.len());
+        let mut items = items;
+        let back = items.split_off(items.len() / 2);
+        for item in items.into_iter().rev() {
+            self.0.push_front(item);
+        }
+        self.0.extend(back);
+    }
+
+    /// Restore the wrapped-around layout of `self.0`, once its items are contiguous at the start of
+    /// the buffer (as [VecDeque::make_contiguous] leaves them for this layout), in place.
+    fn rewrap(&mut self) {
+        let front_len = self.0.len() / 2;
+        self.0.as_mut_slices().0.rotate_left(front_len);
+        // Moves the (now) last `front_len` items before the start of the buffer: to its end.
+        self.0.rotate_right(front_len);
+    }
+
+    /// Whether the items are NOT contiguous in the ring buffer. "Internal": for debugging only.
+    pub fn is_wrapped(&self) -> bool {
+        !self.0.as_slices().1.is_empty()
+    }
+}
+
+impl<'own, T> Extend<T> for OutCollectionVecDequeWrapped<'own, T>
+where
+    T: Out + 'own,
+{
+    /// If the collection is empty, wrap the items around (see [OutCollectionVecDequeWrapped]).
+    /// Otherwise append them.
+    ///
+    /// [Extend::extend_one] only appends (it doesn't rebalance the two halves): items that are
+    /// extended one by one into an empty collection stay contiguous. (The benchmarks don't do that:
+    /// this collection doesn't support [OutCollection::pop], so it's never used as a priority
+    /// queue.)
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.0.is_empty() {
+            self.wrap_around(iter.into_iter().collect());
+        } else {
+            self.0.extend(iter);
+        }
+    }
+    fn extend_one(&mut self, item: T) {
+        self.0.extend_one(item);
+    }
+    fn extend_reserve(&mut self, additional: usize) {
+        self.0.extend_reserve(additional);
+    }
+}
+impl<'own, T> OutCollection<'own, T> for OutCollectionVecDequeWrapped<'own, T>
+where
+    T: Out + 'own,
+{
+    const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
+    const HAS_SORT_UNSTABLE: bool = true;
+    const HAS_SORT: bool = true;
+    const HAS_POP: bool = false;
+    const HAS_BINARY_SEARCH: bool = true;
+
+    fn new() -> Self {
+        Self(VecDeque::new(), PhantomData)
+    }
+    fn with_capacity(capacity: usize) -> Self {
+        Self(VecDeque::with_capacity(capacity), PhantomData)
+    }
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.0.iter()
+    }
+    fn is_sorted(&self) -> bool {
+        let iter = self.0.iter();
+        core::hint::black_box(iter).is_sorted()
+    }
+    fn sort(&mut self) {
+        self.0.make_contiguous().sort();
+        self.rewrap();
+    }
+    fn sort_unstable(&mut self) {
+        self.0.make_contiguous().sort_unstable();
+        self.rewrap();
+    }
+    fn binary_search(&self, x: &T) -> bool {
+        self.0.binary_search(x).is_ok()
+    }
+    fn pop(&mut self) -> Option<T> {
+        unreachable!();
+    }
+}
+
+pub struct OutCollectionVecDequeWrappedIndicator();
+impl OutCollectionIndicator for OutCollectionVecDequeWrappedIndicator {
+    type OutCollectionImpl<'own, T> = OutCollectionVecDequeWrapped<'own, T> where T: Out + 'own;
+}
+// End of: VecDeque-based collections
+
+/// `BinaryHeap`-based collection (a priority queue). Its [OutCollection::sort_unstable] is
+/// [BinaryHeap::into_sorted_vec] (a heap sort, which is not stable - hence no
+/// [OutCollection::sort]). Once sorted, the items are in [OutCollectionBinaryHeap::sorted] (until
+/// more get pushed).
+///
+/// [OutCollection::binary_search] is a linear scan, because a heap has no order to search by (hence
+/// [OutCollection::HAS_BINARY_SEARCH] is `false`, and it's not benchmarked).
+#[derive(Clone)]
+pub struct OutCollectionBinaryHeap<'own, T>
+where
+    T: Out + 'own,
+{
+    pub heap: BinaryHeap<T>,
+    /// Result of the last sort. Empty if not sorted.
+    pub sorted: Vec<T>,
+    _own: PhantomData<&'own ()>,
+}
+
+impl<'own, T> OutCollectionBinaryHeap<'own, T>
+where
+    T: Out + 'own,
+{
+    /// Move any [OutCollectionBinaryHeap::sorted] items back to the heap.
+    fn unsort(&mut self) {
+        if !self.sorted.is_empty() {
+            self.heap.extend(self.sorted.drain(..));
+        }
+    }
+}
+
+impl<'own, T> Extend<T> for OutCollectionBinaryHeap<'own, T>
+where
+    T: Out + 'own,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.unsort();
+        self.heap.extend(iter);
+    }
+    fn extend_one(&mut self, item: T) {
+        self.unsort();
+        self.heap.push(item);
+    }
+    fn extend_reserve(&mut self, additional: usize) {
+        self.heap.reserve(additional);
+    }
+}
+impl<'own, T> OutCollection<'own, T> for OutCollectionBinaryHeap<'own, T>
+where
+    T: Out + 'own,
+{
+    const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
+    const HAS_SORT_UNSTABLE: bool = true;
+    const HAS_SORT: bool = false;
+    const HAS_POP: bool = true;
+    const HAS_BINARY_SEARCH: bool = false;
+
+    fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            sorted: Vec::new(),
+            _own: PhantomData,
+        }
+    }
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(capacity),
+            sorted: Vec::new(),
+            _own: PhantomData,
+        }
+    }
+    fn clear(&mut self) {
+        self.heap.clear();
+        self.sorted.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len() + self.sorted.len()
+    }
+    /// Sorted items (if any), then the heap (in its internal order).
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.sorted.iter().chain(self.heap.iter())
+    }
+    fn is_sorted(&self) -> bool {
+        let iter = self.iter();
+        core::hint::black_box(iter).is_sorted()
+    }
+    /// Stable sort is not available, hence unsupported.
+    fn sort(&mut self) {
+        unreachable!();
+    }
+    fn sort_unstable(&mut self) {
+        self.unsort();
+        self.sorted = core::mem::take(&mut self.heap).into_sorted_vec();
+    }
+    /// Linear scan.
+    fn binary_search(&self, x: &T) -> bool {
+        self.iter().any(|item| item == x)
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.unsort();
+        self.heap.pop()
+    }
+}
+
+pub struct OutCollectionBinaryHeapIndicator();
+impl OutCollectionIndicator for OutCollectionBinaryHeapIndicator {
+    type OutCollectionImpl<'own, T> = OutCollectionBinaryHeap<'own, T> where T: Out + 'own;
+}
+// End of: BinaryHeap-based collection
+
 /// mut slice-based collection.
 ///
 /// This is for benchmarking `cami` without  `alloc` and `std` features, that is, for `no_std` & no
@@ -266,6 +604,8 @@ where
     const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
     const HAS_SORT_UNSTABLE: bool = true;
     const HAS_SORT: bool = false;
+    const HAS_POP: bool = false;
+    const HAS_BINARY_SEARCH: bool = true;
 
     fn new() -> Self {
         Self(Vec::new(), PhantomData)
@@ -290,15 +630,19 @@ where
         let iter = self.slice().iter();
         core::hint::black_box(iter).is_sorted()
     }
+    /// Stable sort of slices requires `alloc`, hence unsupported.
     fn sort(&mut self) {
-        self.mut_slice().sort();
+        unreachable!();
     }
     fn sort_unstable(&mut self) {
-        unreachable!();
+        self.mut_slice().sort_unstable();
     }
     fn binary_search(&self, x: &T) -> bool {
         self.slice().binary_search(x).is_ok()
     }
+    fn pop(&mut self) -> Option<T> {
+        unreachable!();
+    }
 }
 
 pub struct OutCollectionSliceIndicator();
@@ -308,6 +652,438 @@ impl OutCollectionIndicator for OutCollectionSliceIndicator {
 // End of: mut slice-based collection
 //-----
 
+/// Fixed-capacity, array-based collection. Its items are stored inline (on the stack, unless the
+/// collection itself is moved to the heap), so it never allocates.
+///
+/// This is for benchmarking `cami` in `no_std` & no `alloc` settings - like [OutCollectionSlice],
+/// but without any `Vec` behind it. Adding more than `CAPACITY` items [panic]s - see
+/// [crate::spec::BenchSpec::MAX_NUM_ITEMS].
+pub struct OutCollectionArray<'own, T, const CAPACITY: usize>
+where
+    T: Out + 'own,
+{
+    /// The first `len` items are initialized.
+    items: [MaybeUninit<T>; CAPACITY],
+    len: usize,
+    _own: PhantomData<&'own ()>,
+}
+
+impl<'own, T, const CAPACITY: usize> OutCollectionArray<'own, T, CAPACITY>
+where
+    T: Out + 'own,
+{
+    fn slice(&self) -> &[T] {
+        // SAFETY: The first `len` items are initialized.
+        unsafe { core::slice::from_raw_parts(self.items.as_ptr().cast::<T>(), self.len) }
+    }
+
+    fn mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` items are initialized.
+        unsafe { core::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<'own, T, const CAPACITY: usize> Drop for OutCollectionArray<'own, T, CAPACITY>
+where
+    T: Out + 'own,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+impl<'own, T, const CAPACITY: usize> Clone for OutCollectionArray<'own, T, CAPACITY>
+where
+    T: Out + 'own,
+{
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        clone.extend(self.slice().iter().cloned());
+        clone
+    }
+}
+
+impl<'own, T, const CAPACITY: usize> Extend<T> for OutCollectionArray<'own, T, CAPACITY>
+where
+    T: Out + 'own,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.extend_one(item);
+        }
+    }
+    fn extend_one(&mut self, item: T) {
+        assert!(self.len < CAPACITY, "Fixed capacity {CAPACITY} exceeded.");
+        self.items[self.len].write(item);
+        self.len += 1;
+    }
+    fn extend_reserve(&mut self, additional: usize) {
+        assert!(
+            self.len + additional <= CAPACITY,
+            "Fixed capacity {CAPACITY} is too small for {} items.",
+            self.len + additional
+        );
+    }
+}
+impl<'own, T, const CAPACITY: usize> OutCollection<'own, T>
+    for OutCollectionArray<'own, T, CAPACITY>
+where
+    T: Out + 'own,
+{
+    const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
+    const HAS_SORT_UNSTABLE: bool = true;
+    const HAS_SORT: bool = false;
+    const HAS_POP: bool = false;
+    const HAS_BINARY_SEARCH: bool = true;
+
+    fn new() -> Self {
+        Self {
+            items: [const { MaybeUninit::uninit() }; CAPACITY],
+            len: 0,
+            _own: PhantomData,
+        }
+    }
+    /// [panic]s if `capacity` is more than `CAPACITY`.
+    fn with_capacity(capacity: usize) -> Self {
+        assert!(
+            capacity <= CAPACITY,
+            "Fixed capacity {CAPACITY} is too small for {capacity} items."
+        );
+        Self::new()
+    }
+    fn clear(&mut self) {
+        let items: *mut [T] = self.mut_slice();
+        // Set `len` first, so that if dropping an item panics, no item gets dropped twice.
+        self.len = 0;
+        // SAFETY: The items were initialized, and they're not accessible anymore.
+        unsafe { core::ptr::drop_in_place(items) };
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.slice().iter()
+    }
+    fn is_sorted(&self) -> bool {
+        let iter = self.slice().iter();
+        core::hint::black_box(iter).is_sorted()
+    }
+    /// Stable sort of slices requires `alloc`, hence unsupported.
+    fn sort(&mut self) {
+        unreachable!();
+    }
+    fn sort_unstable(&mut self) {
+        self.mut_slice().sort_unstable();
+    }
+    fn binary_search(&self, x: &T) -> bool {
+        self.slice().binary_search(x).is_ok()
+    }
+    fn pop(&mut self) -> Option<T> {
+        unreachable!();
+    }
+}
+
+pub struct OutCollectionArrayIndicator<const CAPACITY: usize>();
+impl<const CAPACITY: usize> OutCollectionIndicator for OutCollectionArrayIndicator<CAPACITY> {
+    type OutCollectionImpl<'own, T> = OutCollectionArray<'own, T, CAPACITY> where T: Out + 'own;
+}
+// End of: array-based collection
+//-----
+
+/// Map from "output" items (keys) to values. Like [OutCollection], but key/value - for
+/// [OutMapIndicator::OutMapImpl].
+pub trait OutMap<'out, K, V>: Clone + Extend<(K, V)>
+where
+    K: Out + 'out,
+    V: Clone + 'out,
+{
+    fn new() -> Self;
+    fn len(&self) -> usize;
+
+    fn get(&self, key: &K) -> Option<&V>;
+    /// All entries, in the order of keys.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a;
+    /// Entries with keys from `from` (inclusive) onwards, in the order of keys.
+    fn range_from<'a>(&'a self, from: &K) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a;
+}
+
+pub trait OutMapIndicator {
+    type OutMapImpl<'own, K, V>: OutMap<'own, K, V>
+    where
+        K: Out + 'own,
+        V: Clone + 'own;
+}
+
+/// `BTreeMap`-based map
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct OutMapBTreeMap<'own, K, V>(pub BTreeMap<K, V>, PhantomData<&'own ()>)
+where
+    K: Out + 'own,
+    V: Clone + 'own;
+
+impl<'own, K, V> Extend<(K, V)> for OutMapBTreeMap<'own, K, V>
+where
+    K: Out + 'own,
+    V: Clone + 'own,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+    fn extend_one(&mut self, item: (K, V)) {
+        self.0.extend_one(item);
+    }
+    fn extend_reserve(&mut self, additional: usize) {
+        self.0.extend_reserve(additional);
+    }
+}
+impl<'own, K, V> OutMap<'own, K, V> for OutMapBTreeMap<'own, K, V>
+where
+    K: Out + 'own,
+    V: Clone + 'own,
+{
+    fn new() -> Self {
+        Self(BTreeMap::new(), PhantomData)
+    }
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.0.get(key)
+    }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.0.iter()
+    }
+    fn range_from<'a>(&'a self, from: &K) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.0.range((Bound::Included(from), Bound::Unbounded))
+    }
+}
+
+pub struct OutMapBTreeMapIndicator();
+impl OutMapIndicator for OutMapBTreeMapIndicator {
+    type OutMapImpl<'own, K, V> = OutMapBTreeMap<'own, K, V> where K: Out + 'own, V: Clone + 'own;
+}
+// End of: BTreeMap-based map
+//-----
+
+#[cfg(feature = "std")]
+pub use hashed::*;
+
+/// Hash-based collections: baselines (not ordered at all), to compare [Cami]-sorted lookup with.
+#[cfg(feature = "std")]
+mod hashed {
+    use super::{Out, OutCollection};
+    use core::hash::{BuildHasherDefault, Hash};
+    use core::marker::PhantomData;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{HashMap, HashSet};
+
+    /// Deterministic (unlike [std::collections::hash_map::RandomState]), so that benchmark runs
+    /// are repeatable.
+    pub type OutBuildHasher = BuildHasherDefault<DefaultHasher>;
+
+    /// Like [super::OutCollectionIndicator], but for hash-based collections, which need [Hash]. (A
+    /// generic [super::OutCollectionIndicator::OutCollectionImpl] can't require it.)
+    pub trait OutHashIndicator {
+        type OutCollectionImpl<'own, T>: OutCollection<'own, T>
+        where
+            T: Out + Hash + 'own;
+    }
+
+    /// `HashSet`-based collection. It has no order: it can't sort (and it doesn't need to), and its
+    /// [OutCollection::binary_search] is a hash lookup.
+    #[derive(Clone)]
+    #[repr(transparent)]
+    pub struct OutCollectionHashSet<'own, T>(pub HashSet<T, OutBuildHasher>, PhantomData<&'own ()>)
+    where
+        T: Out + Hash + 'own;
+
+    impl<'own, T> Extend<T> for OutCollectionHashSet<'own, T>
+    where
+        T: Out + Hash + 'own,
+    {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            self.0.extend(iter);
+        }
+        fn extend_one(&mut self, item: T) {
+            self.0.extend_one(item);
+        }
+        fn extend_reserve(&mut self, additional: usize) {
+            self.0.extend_reserve(additional);
+        }
+    }
+    impl<'own, T> OutCollection<'own, T> for OutCollectionHashSet<'own, T>
+    where
+        T: Out + Hash + 'own,
+    {
+        const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = false;
+        const HAS_SORT_UNSTABLE: bool = false;
+        const HAS_SORT: bool = false;
+        const HAS_POP: bool = false;
+        const HAS_BINARY_SEARCH: bool = true;
+
+        fn new() -> Self {
+            Self(HashSet::default(), PhantomData)
+        }
+        fn with_capacity(capacity: usize) -> Self {
+            Self(
+                HashSet::with_capacity_and_hasher(capacity, OutBuildHasher::default()),
+                PhantomData,
+            )
+        }
+        fn clear(&mut self) {
+            self.0.clear();
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+        fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+        where
+            T: 'a,
+        {
+            self.0.iter()
+        }
+        /// Whether the (arbitrary) iteration order happens to be sorted.
+        fn is_sorted(&self) -> bool {
+            let iter = self.0.iter();
+            core::hint::black_box(iter).is_sorted()
+        }
+        fn sort(&mut self) {
+            unreachable!();
+        }
+        fn sort_unstable(&mut self) {
+            unreachable!();
+        }
+        fn binary_search(&self, x: &T) -> bool {
+            self.0.contains(x)
+        }
+        fn pop(&mut self) -> Option<T> {
+            unreachable!();
+        }
+    }
+
+    pub struct OutCollectionHashSetIndicator();
+    impl OutHashIndicator for OutCollectionHashSetIndicator {
+        type OutCollectionImpl<'own, T> = OutCollectionHashSet<'own, T> where T: Out + Hash + 'own;
+    }
+
+    /// `HashMap`-based collection: item => number of its occurrences, so that it allows multiple
+    /// equal items. Otherwise like [OutCollectionHashSet].
+    #[derive(Clone)]
+    pub struct OutCollectionHashMap<'own, T>
+    where
+        T: Out + Hash + 'own,
+    {
+        pub counts: HashMap<T, usize, OutBuildHasher>,
+        /// Sum of `counts`' values.
+        len: usize,
+        _own: PhantomData<&'own ()>,
+    }
+
+    impl<'own, T> Extend<T> for OutCollectionHashMap<'own, T>
+    where
+        T: Out + Hash + 'own,
+    {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            for item in iter {
+                self.extend_one(item);
+            }
+        }
+        fn extend_one(&mut self, item: T) {
+            *self.counts.entry(item).or_insert(0) += 1;
+            self.len += 1;
+        }
+        fn extend_reserve(&mut self, additional: usize) {
+            self.counts.reserve(additional);
+        }
+    }
+    impl<'own, T> OutCollection<'own, T> for OutCollectionHashMap<'own, T>
+    where
+        T: Out + Hash + 'own,
+    {
+        const ALLOWS_MULTIPLE_EQUAL_ITEMS: bool = true;
+        const HAS_SORT_UNSTABLE: bool = false;
+        const HAS_SORT: bool = false;
+        const HAS_POP: bool = false;
+        const HAS_BINARY_SEARCH: bool = true;
+
+        fn new() -> Self {
+            Self {
+                counts: HashMap::default(),
+                len: 0,
+                _own: PhantomData,
+            }
+        }
+        fn with_capacity(capacity: usize) -> Self {
+            Self {
+                counts: HashMap::with_capacity_and_hasher(capacity, OutBuildHasher::default()),
+                len: 0,
+                _own: PhantomData,
+            }
+        }
+        fn clear(&mut self) {
+            self.counts.clear();
+            self.len = 0;
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+        /// Each item as many times as it occurs.
+        fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+        where
+            T: 'a,
+        {
+            self.counts
+                .iter()
+                .flat_map(|(item, &count)| core::iter::repeat(item).take(count))
+        }
+        /// Whether the (arbitrary) iteration order happens to be sorted.
+        fn is_sorted(&self) -> bool {
+            let iter = self.iter();
+            core::hint::black_box(iter).is_sorted()
+        }
+        fn sort(&mut self) {
+            unreachable!();
+        }
+        fn sort_unstable(&mut self) {
+            unreachable!();
+        }
+        fn binary_search(&self, x: &T) -> bool {
+            self.counts.contains_key(x)
+        }
+        fn pop(&mut self) -> Option<T> {
+            unreachable!();
+        }
+    }
+
+    pub struct OutCollectionHashMapIndicator();
+    impl OutHashIndicator for OutCollectionHashMapIndicator {
+        type OutCollectionImpl<'own, T> = OutCollectionHashMap<'own, T> where T: Out + Hash + 'own;
+    }
+}
+// End of: hash-based collections
+//-----
+
 pub type OutRetriever<'own, OutIndicatorIndicatorImpl, Sub> =
     <<OutIndicatorIndicatorImpl as OutIndicatorIndicator>::OutIndicatorImpl<
         'own,
@@ -0,0 +1,127 @@
+//! Strings with shared prefixes. Lexicographic comparison costs most when items share long
+//! prefixes - and that's where Cami should shine. [crate::data::Data::string] produces independent
+//! random strings, so their prefixes (almost) never collide.
+use crate::chars::CharClass;
+use crate::data::Data;
+use crate::dist::Distribution;
+use crate::prng::split_mix_64;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+const ALPHANUMERIC: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Seed of the root of the (implied) tree.
+const ROOT_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A prefix tree, `depth` levels deep. Each node has `fan_out` children, and each edge (from a
+/// parent to a child) is labelled with a segment of `prefix_len` alphanumeric ASCII characters.
+///
+/// An item is a concatenation of segments along a random path from the root to a leaf, followed by
+/// a random suffix. Hence the items share prefixes of (a multiple of) `prefix_len` characters -
+/// the lower `fan_out`, the more items share them.
+///
+/// The tree is not stored. Instead, the segments are derived from the path (so they are the same
+/// for any seed). Only the choice of the path (and the suffix) come from [Data].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrefixTree {
+    pub depth: usize,
+    pub fan_out: usize,
+    pub prefix_len: usize,
+}
+
+impl PrefixTree {
+    /// Max. number of characters shared by two items (apart from any accidentally equal start of
+    /// their suffixes).
+    pub const fn common_prefix_len(&self) -> usize {
+        self.depth * self.prefix_len
+    }
+
+    /// The prefix (as per a randomly chosen path), followed by a suffix whose length (in chars) is
+    /// per `suffix_len_dist`, with characters of `suffix_class` - as in
+    /// [Data::string_for_len_dist_class].
+    pub fn string<D: Data + ?Sized>(
+        &self,
+        data: &mut D,
+        suffix_len_dist: Distribution,
+        suffix_class: CharClass,
+    ) -> String {
+        let suffix = data.string_for_len_dist_class(suffix_len_dist, suffix_class);
+        let mut result = String::with_capacity(self.common_prefix_len() + suffix.len());
+
+        let mut node_seed = ROOT_SEED;
+        for _ in 0..self.depth {
+            let child = data.usize(0..self.fan_out);
+            node_seed = split_mix_64(node_seed.wrapping_add(child as u64 + 1));
+            let mut segment_state = node_seed;
+            for _ in 0..self.prefix_len {
+                segment_state = split_mix_64(segment_state);
+                result.push(
+                    ALPHANUMERIC[(segment_state % ALPHANUMERIC.len() as u64) as usize] as char,
+                );
+            }
+        }
+        result.push_str(&suffix);
+        result
+    }
+
+    /// Like [PrefixTree::string], but as bytes.
+    pub fn bytes<D: Data + ?Sized>(
+        &self,
+        data: &mut D,
+        suffix_len_dist: Distribution,
+        suffix_class: CharClass,
+    ) -> Vec<u8> {
+        self.string(data, suffix_len_dist, suffix_class)
+            .into_bytes()
+    }
+}
+
+impl Default for PrefixTree {
+    /// 4 levels, each with 4 children, each with 8 characters: 256 leaves. Items sharing a leaf
+    /// share 32 characters.
+    fn default() -> Self {
+        Self {
+            depth: 4,
+            fan_out: 4,
+            prefix_len: 8,
+        }
+    }
+}
+
+/// The same format as [FromStr].
+impl Display for PrefixTree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.depth, self.fan_out, self.prefix_len)
+    }
+}
+
+/// Parse `<depth>:<fan_out>:<prefix_len>`.
+impl FromStr for PrefixTree {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .trim()
+            .split(':')
+            .map(|part| {
+                usize::from_str(part).map_err(|_| {
+                    format!("Prefix tree parameter {part} should be an unsigned integer.")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let [depth, fan_out, prefix_len] = parts[..] {
+            if fan_out == 0 {
+                return Err("Prefix tree fan out should be positive.".to_owned());
+            }
+            Ok(Self {
+                depth,
+                fan_out,
+                prefix_len,
+            })
+        } else {
+            Err(format!(
+                "Prefix tree should be <depth>:<fan_out>:<prefix_len>, but received {s}."
+            ))
+        }
+    }
+}
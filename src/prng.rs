@@ -0,0 +1,202 @@
+//! In-crate pseudo-random generators, with no dependencies. Unlike an external crate (like
+//! `fastrand`), their algorithms are fixed here - so the generated data stays the same for the same
+//! seed, regardless of dependency versions.
+//!
+//! None of them is cryptographically secure - they don't need to be.
+use crate::data::{Data, DataItems};
+use crate::rnd::Random;
+use core::ops::Range;
+
+/// The output function of SplitMix64: the next output for `state`, where `state` is the state
+/// BEFORE it gets incremented. Also useful as a stateless hash.
+pub(crate) fn split_mix_64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(SplitMix64::INCREMENT);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// SplitMix64 (by Sebastiano Vigna), as used by Java's `SplittableRandom`.
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    const INCREMENT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = split_mix_64(self.state);
+        self.state = self.state.wrapping_add(Self::INCREMENT);
+        result
+    }
+}
+
+/// PCG32 (PCG-XSH-RR 64/32, by Melissa O'Neill), seeded as `pcg32_srandom_r` of the reference
+/// implementation.
+#[derive(Clone, Debug)]
+pub struct Pcg32 {
+    state: u64,
+    /// Odd.
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+    /// Stream (sequence) selector, as in the reference demo.
+    const DEFAULT_STREAM: u64 = 54;
+
+    pub fn new(seed: u64) -> Self {
+        Self::new_with_stream(seed, Self::DEFAULT_STREAM)
+    }
+
+    pub fn new_with_stream(seed: u64, stream: u64) -> Self {
+        let mut result = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        result.next_u32();
+        result.state = result.state.wrapping_add(seed);
+        result.next_u32();
+        result
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rotation = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let high = self.next_u32() as u64;
+        (high << 32) | self.next_u32() as u64
+    }
+}
+
+/// xoshiro256** (by David Blackman & Sebastiano Vigna).
+#[derive(Clone, Debug)]
+pub struct Xoshiro256StarStar {
+    state: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Initiate the state from [SplitMix64], as recommended by the authors (so it's never all
+    /// zeros).
+    pub fn new(seed: u64) -> Self {
+        let mut split_mix = SplitMix64::new(seed);
+        Self {
+            state: [(); 4].map(|()| split_mix.next_u64()),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+/// Uniform in `range` (which must not be empty), from `next_u64`. Unbiased (Lemire's method with
+/// rejection).
+fn usize_in(range: Range<usize>, mut next_u64: impl FnMut() -> u64) -> usize {
+    assert!(!range.is_empty(), "Empty range {range:?}.");
+    let len = (range.end - range.start) as u64;
+    let threshold = len.wrapping_neg() % len;
+    loop {
+        let product = next_u64() as u128 * len as u128;
+        if product as u64 >= threshold {
+            return range.start + (product >> 64) as usize;
+        }
+    }
+}
+
+/// Implement [Data] and [DataItems] for a generator that has `next_u64()`.
+macro_rules! impl_data {
+    ($prng:ty) => {
+        impl Data for $prng {
+            fn u8(&mut self, range: Range<u8>) -> u8 {
+                self.usize(range.start as usize..range.end as usize) as u8
+            }
+            fn usize(&mut self, range: Range<usize>) -> usize {
+                usize_in(range, || self.next_u64())
+            }
+            /// 53 random bits, as per the precision of [f64].
+            fn f64(&mut self) -> f64 {
+                (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+            }
+        }
+
+        impl DataItems for $prng {}
+    };
+}
+
+impl_data!(SplitMix64);
+impl_data!(Pcg32);
+impl_data!(Xoshiro256StarStar);
+
+impl Random for SplitMix64 {
+    const SEED_WORDS: usize = 1;
+
+    fn with_seed_words(words: &[u64]) -> Result<Self, String> {
+        match *words {
+            [seed] => Ok(Self::new(seed)),
+            _ => Err(format!(
+                "splitmix64 takes a seed of 1 word, but received {}.",
+                words.len()
+            )),
+        }
+    }
+}
+
+impl Random for Pcg32 {
+    /// Seed, and optionally a stream (sequence) selector.
+    const SEED_WORDS: usize = 2;
+
+    fn with_seed_words(words: &[u64]) -> Result<Self, String> {
+        match *words {
+            [seed] => Ok(Self::new(seed)),
+            [seed, stream] => Ok(Self::new_with_stream(seed, stream)),
+            _ => Err(format!(
+                "pcg32 takes a seed of 1 or 2 words, but received {}.",
+                words.len()
+            )),
+        }
+    }
+}
+
+impl Random for Xoshiro256StarStar {
+    /// Either one word (expanded by [SplitMix64] - see [Xoshiro256StarStar::new]), or the whole
+    /// state (four words).
+    const SEED_WORDS: usize = 4;
+
+    fn with_seed_words(words: &[u64]) -> Result<Self, String> {
+        match *words {
+            [seed] => Ok(Self::new(seed)),
+            [_, _, _, _] if words.iter().all(|&word| word == 0) => {
+                Err("xoshiro256 state (seed of 4 words) must not be all zeros.".to_owned())
+            }
+            [a, b, c, d] => Ok(Self {
+                state: [a, b, c, d],
+            }),
+            _ => Err(format!(
+                "xoshiro256 takes a seed of 1 or 4 words, but received {}.",
+                words.len()
+            )),
+        }
+    }
+}
@@ -1,65 +1,289 @@
+use crate::chars::CharClass;
 use crate::data::{self, Data, DataItems};
+use crate::fingerprint;
+use crate::prng::{split_mix_64, Pcg32, SplitMix64, Xoshiro256StarStar};
+use core::fmt::{self, Display, Formatter};
 use core::num;
 use core::ops::Range;
 use core::str::FromStr;
 #[cfg(feature = "fastrand")]
 use fastrand::Rng;
+use std::collections::hash_map::RandomState;
+use std::fs::{self, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub const RND_SEED_DEC_ENV: &str = "RND_SEED_DEC";
 pub const RND_SEED_HEX_ENV: &str = "RND_SEED_HEX";
+pub const RND_BACKEND_ENV: &str = "RND_BACKEND";
+/// If set (to anything but an empty string), and neither `RND_SEED_DEC` nor `RND_SEED_HEX` is set,
+/// a seed gets generated - see [auto_seed_hex].
+pub const RND_SEED_AUTO_ENV: &str = "RND_SEED_AUTO";
+/// Where [auto_seed_hex] logs seeds, relative to the target directory.
+pub const RND_SEED_LOG_FILE: &str = "rnd-seeds.log";
+/// Comma-separated list of master seeds, for multi-seed runs - see [seeds_from_env].
+pub const RND_SEEDS_ENV: &str = "RND_SEEDS";
+/// Number of master seeds to derive, for multi-seed runs - see [seeds_from_env].
+pub const RND_SEED_COUNT_ENV: &str = "RND_SEED_COUNT";
 
 /// We create one instance per set of compared benchmarks. We don't re-use the same instance for all
 /// benchmarks, because we'd need mutable access to such instance, and that's tricky with
 /// `iai-callgrind`'s, `Criterion`'s or other harness's macros. That would prevent benchmarking in
 /// parallel.
 ///
-/// Therefore we require the user to provide a seed.
+/// Therefore we require the user to provide a seed. Data of different purpose (for example, items
+/// vs. queries) should come from separate children - see [Random::fork].
 pub trait Random: Data + Sized {
+    /// Max. number of 64-bit words of a seed. See [Random::with_seed_words].
+    const SEED_WORDS: usize;
+
+    /// Initiate with a seed of 1 to [Random::SEED_WORDS] words (as per [parse_seed]). Which numbers
+    /// of words are accepted (within that range) depends on the implementation.
+    fn with_seed_words(words: &[u64]) -> Result<Self, String>;
+
     /// Initiate with a seed. The seed is parsed from `seed`, which is in decimal representation.
     /// (It comes from environment variable `RND_SEED_DEC`).
     ///
-    /// The actual format of `seed` depends on the implementation (it may be one `u64`, multiple
-    /// `u64`'s separated by whitespace, or other.)
-    fn with_seed_dec(seed: &str) -> Self;
+    /// By default it's 1 to [Random::SEED_WORDS] words - see [parse_seed].
+    fn with_seed_dec(seed: &str) -> Self {
+        parse_seed(seed, 10, Self::SEED_WORDS)
+            .and_then(|words| Self::with_seed_words(&words))
+            .unwrap_or_else(|err| panic!("Environment variable {RND_SEED_DEC_ENV}: {err}"))
+    }
     /// Initiate with a seed. The seed is parsed from `seed`, which is in hexadecimal
     /// representation. (It comes from environment variable `RND_SEED_HEX`).
     ///
-    /// The actual format of `seed` depends on the implementation (it may be one `u64`, multiple
-    /// `u64`'s separated by whitespace, or other.)
-    fn with_seed_hex(seed: &str) -> Self;
+    /// By default it's 1 to [Random::SEED_WORDS] words - see [parse_seed].
+    fn with_seed_hex(seed: &str) -> Self {
+        parse_seed(seed, 16, Self::SEED_WORDS)
+            .and_then(|words| Self::with_seed_words(&words))
+            .unwrap_or_else(|err| panic!("Environment variable {RND_SEED_HEX_ENV}: {err}"))
+    }
 
     /// Initiate with a seed, by default from an environment variable `RND_SEED_DEC` or
-    /// `RND_SEED_HEX` - see [Random::with_seed_dec] and [Random::with_seed_hex]. Override this only
-    /// for tests or special.
+    /// `RND_SEED_HEX` - see [Random::with_seed_dec] and [Random::with_seed_hex]. If neither is set,
+    /// but `RND_SEED_AUTO` is, use [auto_seed_hex]. Override this only for tests or special.
     fn with_seed() -> Self {
-        let seed_dec = std::env::var(RND_SEED_DEC_ENV);
-        let seed_hex = std::env::var(RND_SEED_HEX_ENV);
-        if seed_dec.is_ok() && seed_hex.is_ok() {
-            panic!("You've provided both environment variables {RND_SEED_DEC_ENV}: {} and {RND_SEED_HEX_ENV}: {}, but this requires exactly one.", seed_dec.unwrap(), seed_hex.unwrap());
+        match seed_from_env() {
+            (RND_SEED_DEC_ENV, dec) => Self::with_seed_dec(&dec),
+            (_, hex) => Self::with_seed_hex(&hex),
         }
-        if let Ok(dec) = seed_dec {
-            Self::with_seed_dec(&dec)
-        } else if let Ok(hex) = seed_hex {
-            Self::with_seed_hex(&hex)
+    }
+
+    /// Initiate a child generator for `label` (for example, `"items"` or `"queries"`). Its seed is
+    /// derived from the master seed (the same as for [Random::with_seed]) and `label` - see
+    /// [fork_seed_words]. So, children with different labels are independent of each other, and
+    /// each is reproducible regardless of the order in which the children get created (or of how
+    /// much any other child has generated).
+    fn fork(label: &str) -> Self {
+        let (env_var_name, seed) = seed_from_env();
+        let radix = if env_var_name == RND_SEED_DEC_ENV {
+            10
         } else {
-            panic!("Requiring exactly one of two environment variables RND_SEED_DEC, RND_SEED_HEX, but received none.");
+            16
+        };
+        parse_seed(&seed, radix, Self::SEED_WORDS)
+            .and_then(|words| Self::fork_words(&words, label))
+            .unwrap_or_else(|err| panic!("Environment variable {env_var_name}: {err}"))
+    }
+
+    /// Like [Random::fork], but the master seed is `words` (rather than from the environment).
+    fn fork_words(words: &[u64], label: &str) -> Result<Self, String> {
+        Self::with_seed_words(&fork_seed_words(words, label))
+    }
+}
+
+/// Name & value of the environment variable with the master seed: `RND_SEED_DEC` or `RND_SEED_HEX`.
+/// If neither is set, but `RND_SEED_AUTO` is, then `RND_SEED_HEX` with [auto_seed_hex].
+pub fn seed_from_env() -> (&'static str, String) {
+    let seed_dec = std::env::var(RND_SEED_DEC_ENV);
+    let seed_hex = std::env::var(RND_SEED_HEX_ENV);
+    if seed_dec.is_ok() && seed_hex.is_ok() {
+        panic!("You've provided both environment variables {RND_SEED_DEC_ENV}: {} and {RND_SEED_HEX_ENV}: {}, but this requires exactly one.", seed_dec.unwrap(), seed_hex.unwrap());
+    }
+    if let Ok(dec) = seed_dec {
+        (RND_SEED_DEC_ENV, dec)
+    } else if let Ok(hex) = seed_hex {
+        (RND_SEED_HEX_ENV, hex)
+    } else if seed_is_auto() {
+        (RND_SEED_HEX_ENV, auto_seed_hex().to_owned())
+    } else {
+        panic!("Requiring exactly one of two environment variables RND_SEED_DEC, RND_SEED_HEX, but received none. (Or set RND_SEED_AUTO=1 to generate a seed.)");
+    }
+}
+
+/// Whether [seed_from_env] generates the seed (with [auto_seed_hex]): `RND_SEED_AUTO` is set (to
+/// anything but an empty string), and neither `RND_SEED_DEC` nor `RND_SEED_HEX` is.
+pub fn seed_is_auto() -> bool {
+    std::env::var(RND_SEED_DEC_ENV).is_err()
+        && std::env::var(RND_SEED_HEX_ENV).is_err()
+        && std::env::var(RND_SEED_AUTO_ENV).is_ok_and(|auto| !auto.is_empty())
+}
+
+/// Master seeds (each of 1 to `max_words` words) for a multi-seed run:
+/// - if `RND_SEEDS` is set: a comma-separated list of seeds, each in the same format as
+///   `RND_SEED_DEC` (see [parse_seed]), for example `1, 2, 0x2A 7`,
+/// - otherwise, if `RND_SEED_COUNT` is set to `N`: `N` seeds derived from the master seed (per
+///   [seed_from_env]), with [fork_seed_words] for labels `seed0`, `seed1`...,
+/// - otherwise `None` (a single-seed run).
+pub fn seeds_from_env(max_words: usize) -> Option<Vec<Vec<u64>>> {
+    if let Ok(seeds) = std::env::var(RND_SEEDS_ENV) {
+        let seeds = seeds
+            .split(',')
+            .map(|seed| parse_seed(seed, 10, max_words))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|err| panic!("Environment variable {RND_SEEDS_ENV}: {err}"));
+        return Some(seeds);
+    }
+    let count = std::env::var(RND_SEED_COUNT_ENV).ok()?;
+    let count = usize::from_str(&count).ok().filter(|&count| count > 0).unwrap_or_else(|| {
+        panic!("Environment variable {RND_SEED_COUNT_ENV} should be a positive integer, but received {count}.")
+    });
+    let (env_var_name, seed) = seed_from_env();
+    let radix = if env_var_name == RND_SEED_DEC_ENV {
+        10
+    } else {
+        16
+    };
+    let master = parse_seed(&seed, radix, max_words)
+        .unwrap_or_else(|err| panic!("Environment variable {env_var_name}: {err}"));
+    Some(
+        (0..count)
+            .map(|index| fork_seed_words(&master, &format!("seed{index}")))
+            .collect(),
+    )
+}
+
+/// `words` in the format of `RND_SEED_HEX`, so that they can be exported to reproduce a run.
+pub fn format_seed_hex(words: &[u64]) -> String {
+    words
+        .iter()
+        .map(|word| format!("{word:x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Seed words of a child generator for `label` - see [Random::fork]. The same number of words as
+/// `words`. Each word is hashed (with [SplitMix64](crate::prng::SplitMix64)'s output function)
+/// together with its index and with a hash (FNV-1a) of `label`.
+pub fn fork_seed_words(words: &[u64], label: &str) -> Vec<u64> {
+    let label_hash = fingerprint::fnv_1a(fingerprint::FNV_OFFSET_BASIS, label.as_bytes());
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, &word)| {
+            split_mix_64(word ^ split_mix_64(label_hash.wrapping_add(index as u64)))
+        })
+        .collect()
+}
+
+/// A seed (of one word, in hexadecimal) generated from OS entropy (via
+/// [std::collections::hash_map::RandomState]), time and process ID. It's generated once per process
+/// (so all benchmarks in the process share it). It's printed, and appended (with the bench name)
+/// to `target/rnd-seeds.log` (or `$CARGO_TARGET_DIR/rnd-seeds.log`), so that the run can be
+/// reproduced by exporting the logged `RND_SEED_HEX=...`.
+///
+/// `iai-callgrind` runs each benchmark in a separate process, so there each benchmark would get a
+/// different seed - hence [crate::shared_iai::own_and_out] rejects it.
+pub fn auto_seed_hex() -> &'static str {
+    static AUTO_SEED_HEX: OnceLock<String> = OnceLock::new();
+    AUTO_SEED_HEX.get_or_init(|| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(now.as_nanos());
+        hasher.write_u32(std::process::id());
+        let seed_hex = format!("{:x}", hasher.finish());
+
+        let bench = bench_name();
+        let target_dir = data::target_dir();
+        let log_path = target_dir.join(RND_SEED_LOG_FILE);
+        println!(
+            "{bench}: generated seed {RND_SEED_HEX_ENV}={seed_hex} (logged in {}).",
+            log_path.display()
+        );
+
+        let logged = fs::create_dir_all(&target_dir).and_then(|()| {
+            let mut log = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&log_path)?;
+            writeln!(
+                log,
+                "{} {bench} {RND_SEED_HEX_ENV}={seed_hex}",
+                now.as_secs()
+            )
+        });
+        if let Err(err) = logged {
+            eprintln!("Couldn't log the seed to {}: {err}", log_path.display());
+        }
+        seed_hex
+    })
+}
+
+/// Name of the current executable, without any hash suffix that `cargo` adds.
+fn bench_name() -> String {
+    let exe = std::env::current_exe().unwrap_or_default();
+    let stem = exe.file_stem().unwrap_or_default().to_string_lossy();
+    match stem.rsplit_once('-') {
+        Some((name, hash)) if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+            name.to_owned()
         }
+        _ => stem.into_owned(),
     }
 }
 
+/// Parse 1 to `max_words` 64-bit words of a seed, separated by whitespace. Each word is in `radix`
+/// (10 or 16), unless it has prefix `0x`, which means hexadecimal. Digits may be separated by `_`.
+/// For example: `0xDEAD_BEEF 1_000_000`.
+pub fn parse_seed(seed: &str, radix: u32, max_words: usize) -> Result<Vec<u64>, String> {
+    let words = seed
+        .split_whitespace()
+        .map(|word| {
+            let (digits, radix) = match word.strip_prefix("0x").or(word.strip_prefix("0X")) {
+                Some(digits) => (digits, 16),
+                None => (word, radix),
+            };
+            let digits = digits.replace('_', "");
+            u64::from_str_radix(&digits, radix).map_err(|_| {
+                format!("Seed word {word} should be a 64-bit unsigned integer in base {radix}.")
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if words.is_empty() || words.len() > max_words {
+        return Err(format!(
+            "Seed should have 1 to {max_words} words (separated by whitespace), but received {}: {seed}.",
+            words.len()
+        ));
+    }
+    Ok(words)
+}
+
 #[cfg(feature = "fastrand")]
 impl Data for Rng {
     fn u8(&mut self, range: Range<u8>) -> u8 {
         Rng::u8(self, range)
     }
-    fn char(&mut self) -> char {
-        Rng::alphanumeric(self)
+    /// [CharClass::AsciiAlphanumeric] uses [Rng::alphanumeric], so that data generated before
+    /// character classes were configurable stays the same.
+    fn char_for_class(&mut self, class: CharClass) -> char {
+        match class {
+            CharClass::AsciiAlphanumeric => Rng::alphanumeric(self),
+            _ => class.sample(self),
+        }
     }
     fn usize(&mut self, range: Range<usize>) -> usize {
         Rng::usize(self, range)
     }
+    fn f64(&mut self) -> f64 {
+        Rng::f64(self)
+    }
 }
 
+#[cfg(feature = "fastrand")]
 impl DataItems for Rng {}
 /*
     fn num_items(&mut self) -> usize {
@@ -69,10 +293,159 @@ impl DataItems for Rng {}
 
 #[cfg(feature = "fastrand")]
 impl Random for Rng {
-    fn with_seed_dec(seed: &str) -> Self {
-        Rng::with_seed(u64::from_str(seed).expect("Environment variable RND_SEED_DEC should be a 64-bit unsigned integer in decimal representation."))
+    const SEED_WORDS: usize = 1;
+
+    fn with_seed_words(words: &[u64]) -> Result<Self, String> {
+        match *words {
+            [seed] => Ok(Rng::with_seed(seed)),
+            _ => Err(format!(
+                "fastrand takes a seed of 1 word, but received {}.",
+                words.len()
+            )),
+        }
     }
-    fn with_seed_hex(seed: &str) -> Self {
-        Rng::with_seed(u64::from_str_radix(seed, 16).expect("Environment variable RND_SEED_HEX should be a 64-bit unsigned integer in hexadecimal representation."))
+}
+
+/// Which generator [BenchRnd] uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RndBackend {
+    SplitMix64,
+    Pcg32,
+    /// The default: fast, and with a large state.
+    #[default]
+    Xoshiro256StarStar,
+    /// Available with feature `fastrand` only. Its algorithm may change between versions of
+    /// `fastrand` (and so may the data).
+    Fastrand,
+}
+
+impl RndBackend {
+    /// Per environment variable `RND_BACKEND`. If not present, use [RndBackend::default].
+    pub fn from_env() -> Self {
+        match std::env::var(RND_BACKEND_ENV) {
+            Ok(st) => Self::from_str(&st)
+                .unwrap_or_else(|err| panic!("Environment variable {RND_BACKEND_ENV}: {err}")),
+            Err(_) => Self::default(),
+        }
+    }
+}
+
+/// The same format as [FromStr].
+impl Display for RndBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RndBackend::SplitMix64 => write!(f, "splitmix64"),
+            RndBackend::Pcg32 => write!(f, "pcg32"),
+            RndBackend::Xoshiro256StarStar => write!(f, "xoshiro256"),
+            RndBackend::Fastrand => write!(f, "fastrand"),
+        }
+    }
+}
+
+/// Parse `splitmix64`, `pcg32`, `xoshiro256` or `fastrand`.
+impl FromStr for RndBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "splitmix64" => Ok(RndBackend::SplitMix64),
+            "pcg32" => Ok(RndBackend::Pcg32),
+            "xoshiro256" => Ok(RndBackend::Xoshiro256StarStar),
+            #[cfg(feature = "fastrand")]
+            "fastrand" => Ok(RndBackend::Fastrand),
+            #[cfg(not(feature = "fastrand"))]
+            "fastrand" => Err("Randomness backend fastrand requires feature fastrand.".to_owned()),
+            other => Err(format!(
+                "Unknown randomness backend {other}. Expecting one of: splitmix64, pcg32, xoshiro256, fastrand."
+            )),
+        }
+    }
+}
+
+/// Generator used by benches: one of the backends, per [RndBackend::from_env]. So benches don't
+/// require feature `fastrand`.
+#[derive(Clone, Debug)]
+pub enum BenchRnd {
+    SplitMix64(SplitMix64),
+    Pcg32(Pcg32),
+    Xoshiro256StarStar(Xoshiro256StarStar),
+    #[cfg(feature = "fastrand")]
+    Fastrand(Rng),
+}
+
+impl BenchRnd {
+    pub fn backend(&self) -> RndBackend {
+        match self {
+            BenchRnd::SplitMix64(_) => RndBackend::SplitMix64,
+            BenchRnd::Pcg32(_) => RndBackend::Pcg32,
+            BenchRnd::Xoshiro256StarStar(_) => RndBackend::Xoshiro256StarStar,
+            #[cfg(feature = "fastrand")]
+            BenchRnd::Fastrand(_) => RndBackend::Fastrand,
+        }
+    }
+}
+
+/// Dispatch `$call` to the backend.
+macro_rules! dispatch {
+    ($self:ident, $rnd:ident => $call:expr) => {
+        match $self {
+            BenchRnd::SplitMix64($rnd) => $call,
+            BenchRnd::Pcg32($rnd) => $call,
+            BenchRnd::Xoshiro256StarStar($rnd) => $call,
+            #[cfg(feature = "fastrand")]
+            BenchRnd::Fastrand($rnd) => $call,
+        }
+    };
+}
+
+impl Data for BenchRnd {
+    fn u8(&mut self, range: Range<u8>) -> u8 {
+        dispatch!(self, rnd => rnd.u8(range))
+    }
+    fn char_for_class(&mut self, class: CharClass) -> char {
+        dispatch!(self, rnd => rnd.char_for_class(class))
+    }
+    fn usize(&mut self, range: Range<usize>) -> usize {
+        dispatch!(self, rnd => rnd.usize(range))
+    }
+    fn f64(&mut self) -> f64 {
+        dispatch!(self, rnd => rnd.f64())
+    }
+}
+
+impl DataItems for BenchRnd {}
+
+impl Random for BenchRnd {
+    /// The most of any backend. Each backend accepts its own number of words (see
+    /// [BenchRnd::with_seed_words]).
+    const SEED_WORDS: usize = Xoshiro256StarStar::SEED_WORDS;
+
+    /// Initiate the backend per [RndBackend::from_env].
+    fn with_seed_words(words: &[u64]) -> Result<Self, String> {
+        Ok(match RndBackend::from_env() {
+            RndBackend::SplitMix64 => BenchRnd::SplitMix64(with_backend_seed_words(words)?),
+            RndBackend::Pcg32 => BenchRnd::Pcg32(with_backend_seed_words(words)?),
+            RndBackend::Xoshiro256StarStar => {
+                BenchRnd::Xoshiro256StarStar(with_backend_seed_words(words)?)
+            }
+            #[cfg(feature = "fastrand")]
+            RndBackend::Fastrand => BenchRnd::Fastrand(with_backend_seed_words(words)?),
+            #[cfg(not(feature = "fastrand"))]
+            RndBackend::Fastrand => unreachable!(),
+        })
+    }
+}
+
+/// Like [Random::with_seed_words] of `Backend`, but check the number of words first (since
+/// [BenchRnd::SEED_WORDS] may be more than the backend's).
+fn with_backend_seed_words<Backend: Random>(words: &[u64]) -> Result<Backend, String> {
+    if words.len() > Backend::SEED_WORDS {
+        return Err(format!(
+            "Randomness backend {} takes at most {} seed word(s), but received {}.",
+            RndBackend::from_env(),
+            Backend::SEED_WORDS,
+            words.len()
+        ));
     }
+    Backend::with_seed_words(words)
 }
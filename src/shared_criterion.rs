@@ -1,5 +1,22 @@
+use crate::col;
+use crate::data::OwnAndOut;
+use crate::dataset_stats::DatasetStats;
+use crate::fingerprint::ItemBytes;
+use crate::outish::OutCollection;
+use crate::rnd::{self, Random, RND_SEED_HEX_ENV};
+use crate::spec::{
+    BenchSpec, MapOperation, MapSpec, Operation, Ordering, SpecOut, SpecOutColl, SpecOutCollCami,
+    SpecOutMap, SpecOutMapCami,
+};
+#[cfg(feature = "std")]
+use crate::spec::{HashSpec, SpecOutHash, HASH_LOOKUP_FUNCTION};
+use cami::Cami;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::hint;
 use core::time::Duration;
-use criterion::{BenchmarkId, Criterion};
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion};
 
 /// Speed up. Why? This functionality is simple. It should warm up (flood the caches), and show a
 /// benefit, fast.
@@ -9,279 +26,251 @@ pub fn criterion_config() -> Criterion {
         .measurement_time(Duration::from_millis(1000))
 }
 
-/// `OwnType` needs to be [Ord] only if `generate_own_item` can generate (some) equal items AND if
-/// the indicated [OutCollection] has [OutCollection::ALLOWS_MULTIPLE_EQUAL_ITEMS] being `false`.
-pub fn bench_vec_sort_bin_search<
-    OwnType: Ord,
-    SubType: Out,
-    OutIndicatorIndicatorImpl: OutIndicatorIndicator,
-    OutCollectionIndicatorImpl: OutCollectionIndicator,
-    Rnd: Random,
-    IdState,
->(
-    critty: &mut Criterion,
-    rnd: &mut Rnd,
-    group_name: impl Into<String>,
-    id_state: &mut IdState,
-    generate_id_postfix: impl Fn(&IdState) -> String,
-    generate_own_item: impl Fn(&mut Rnd, &mut IdState) -> OwnType,
-    generate_out_item: impl Fn(&OwnType) -> OutRetriever<'_, OutIndicatorIndicatorImpl, SubType>,
-) {
-    let num_items = rnd.usize(MIN_ITEMS..MAX_ITEMS);
-
-    let mut own_items = Vec::with_capacity(num_items);
-    for _ in 0..num_items {
-        let item = generate_own_item(rnd, id_state);
-        own_items.push(item);
+/// Datasets to benchmark on, each in its own group: one per seed if [rnd::seeds_from_env] gives
+/// seeds (then print each seed, as `RND_SEED_HEX`, to reproduce it), otherwise just
+/// [BenchSpec::own_and_out].
+fn own_and_outs<Spec: BenchSpec>() -> Vec<OwnAndOut<Spec::Own, SpecOut<Spec>>>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    match rnd::seeds_from_env(<Spec::Rnd as Random>::SEED_WORDS) {
+        Some(seeds) => seeds
+            .iter()
+            .map(|seed| {
+                let own_and_out = Spec::own_and_out_for_seed(seed);
+                println!(
+                    "{}: {RND_SEED_HEX_ENV}=\"{}\"",
+                    Spec::group_name_for(&own_and_out),
+                    rnd::format_seed_hex(seed)
+                );
+                own_and_out
+            })
+            .collect(),
+        None => vec![Spec::own_and_out()],
     }
-
-    bench_vec_sort_bin_search_own_items::<
-        OwnType,
-        SubType,
-        OutIndicatorIndicatorImpl,
-        OutCollectionIndicatorImpl,
-        Rnd,
-        IdState,
-    >(
-        own_items,
-        critty,
-        rnd,
-        group_name,
-        id_state,
-        generate_id_postfix,
-        generate_out_item,
-    );
-}
-
-/// This removes any extra equal items from `own_items` if the indicated [OutCollection] has
-/// [OutCollection::ALLOWS_MULTIPLE_EQUAL_ITEMS] being `false`. No guarantee as to which one of any
-/// two (or more) equal items will stay.
-pub fn bench_vec_sort_bin_search_own_items<
-    OwnType: Ord,
-    SubType: Out,
-    OutIndicatorIndicatorImpl: OutIndicatorIndicator,
-    OutCollectionIndicatorImpl: OutCollectionIndicator,
-    Rnd: Random,
-    IdState,
->(
-    mut own_items: Vec<OwnType>,
-    critty: &mut Criterion,
-    rnd: &mut Rnd,
-    group_name: impl Into<String>,
-    id_state: &IdState,
-    generate_id_postfix: impl Fn(&IdState) -> String,
-    generate_out_item: impl Fn(&OwnType) -> OutRetriever<'_, OutIndicatorIndicatorImpl, SubType>,
-) {
-    bench_vec_sort_bin_search_ref_possibly_duplicates::<
-        OwnType,
-        OutRetriever<'_, OutIndicatorIndicatorImpl, SubType>,
-        OutCollRetriever<'_, OutCollectionIndicatorImpl, OutIndicatorIndicatorImpl, SubType>,
-        Rnd,
-        IdState,
-    >(
-        &mut own_items,
-        critty,
-        rnd,
-        group_name,
-        id_state,
-        generate_id_postfix,
-        generate_out_item,
-    );
 }
 
-pub fn bench_vec_sort_bin_search_ref_possibly_duplicates<
-    'own,
-    OwnType: Ord + 'own,
-    // No need for SubType from this level deeper.
-    //
-    // Two "retrieved" types:
-    OutType: Out + 'own,
-    OutCollectionType: OutCollection<'own, OutType> + 'own,
-    // No need for type indicators from this level deeper.
-    Rnd: Random,
-    IdState,
->(
-    own_items: &'own mut Vec<OwnType>,
-    critty: &mut Criterion,
-    rnd: &mut Rnd,
-    group_name: impl Into<String>,
-    id_state: &IdState,
-    generate_id_postfix: impl Fn(&IdState) -> String,
-    generate_out_item: impl Fn(&'own OwnType) -> OutType,
-) {
-    if !OutCollectionType::ALLOWS_MULTIPLE_EQUAL_ITEMS {
-        // Remove duplicates. Yes, the result may have fewer items than planned/configured.
-        let mut set = BTreeSet::<OwnType>::new();
-        set.extend(own_items.drain(..));
-        own_items.extend(set.into_iter());
+/// Benchmark all [BenchSpec::operations] of `Spec`, both lexicographic and [Cami]. The data comes
+/// from [BenchSpec::own_and_out] - or, for a multi-seed run (see [rnd::seeds_from_env]), from
+/// [BenchSpec::own_and_out_for_seed] for each seed, with a group per seed.
+///
+/// Each benchmark has ID `<group>/<function>/<id>`, where `<group>` is [BenchSpec::group_name_for]
+/// (so it includes the dataset fingerprint),
+/// `<function>` is [Operation::function] and `<id>` is [Operation::id] - the same as the function
+/// names & IDs generated by [crate::iai_bench_matrix].
+///
+/// [DatasetStats] of the data get printed (to `stdout`) before the group's benchmarks.
+pub fn bench_spec<Spec: BenchSpec>(critty: &mut Criterion)
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    for own_and_out in own_and_outs::<Spec>() {
+        let group_name = Spec::group_name_for(&own_and_out);
+        println!("{group_name}: {}", DatasetStats::new(own_and_out.out));
+        let mut group = critty.benchmark_group(group_name);
+        bench_operations::<Spec>(&mut group, &own_and_out);
+        group.finish();
     }
-
-    bench_vec_sort_bin_search_ref::<OwnType, OutType, OutCollectionType, Rnd, IdState>(
-        own_items,
-        critty,
-        rnd,
-        group_name,
-        id_state,
-        generate_id_postfix,
-        generate_out_item,
-    );
 }
 
-pub fn bench_vec_sort_bin_search_ref<
-    'own,
-    OwnType: Ord + 'own,
-    OutType: Out + 'own,
-    OutCollectionType: OutCollection<'own, OutType> + 'own,
-    Rnd: Random,
-    IdState,
->(
-    own_items: &'own Vec<OwnType>,
-    critty: &mut Criterion,
-    rnd: &mut Rnd,
-    group_name: impl Into<String>,
-    id_state: &IdState,
-    generate_id_postfix: impl Fn(&IdState) -> String,
-    // @TODO _generate_out_item
-    _generate_out_item: impl Fn(&'own OwnType) -> OutType,
-) {
-    let mut group = critty.benchmark_group(group_name);
-    {
-        let unsorted_items = {
-            let unsorted_items = OutCollectionType::with_capacity(own_items.len());
-            //unsorted_items.extend(own_items.iter().map(generate_out_item)); ^^
-            unsorted_items
-        };
-
-        own_items.iter().for_each(|_rf| { //@TODO?
-             //generate_out_item(rf); ^^^
-        });
-
-        fn consume_own_ref<'ownsh, OwnishType: Ord + 'ownsh>(_o: &'ownsh OwnishType) {}
-        own_items.iter().for_each(|rf| {
-            consume_own_ref(rf);
-        });
+/// Like [bench_spec], plus [HASH_LOOKUP_FUNCTION] (with benchmark ID of [Operation::BinSearch]):
+/// look up each "out" item in the hash-based baseline collection of `Spec`.
+#[cfg(feature = "std")]
+pub fn bench_hash_spec<Spec: HashSpec>(critty: &mut Criterion)
+where
+    SpecOut<Spec>: ItemBytes + Hash,
+{
+    for own_and_out in own_and_outs::<Spec>() {
+        let out = own_and_out.out;
+        let group_name = Spec::group_name_for(&own_and_out);
+        println!("{group_name}: {}", DatasetStats::new(out));
+        let mut group = critty.benchmark_group(group_name);
+        bench_operations::<Spec>(&mut group, &own_and_out);
 
-        own_items.iter().for_each(|rf| {
-            core::hint::black_box(rf);
-        });
+        let hashed = col::hash_indicated::<SpecOut<Spec>, SpecOutHash<Spec>>(out);
+        group.bench_function(
+            BenchmarkId::new(HASH_LOOKUP_FUNCTION, Operation::BinSearch.id()),
+            |b| {
+                b.iter(|| {
+                    let hashed = hint::black_box(&hashed);
+                    for item in hint::black_box(out.iter()) {
+                        assert!(hint::black_box(hashed.binary_search(item)));
+                    }
+                })
+            },
+        );
+        group.finish();
+    }
+}
 
-        let mut _refs = vec![];
-        _refs.extend(own_items.iter());
+/// Benchmark all [BenchSpec::operations] of `Spec` on `own_and_out`, in `group`.
+fn bench_operations<Spec: BenchSpec>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    own_and_out: &OwnAndOut<Spec::Own, SpecOut<Spec>>,
+) where
+    SpecOut<Spec>: ItemBytes,
+{
+    let out = own_and_out.out;
+    for operation in Spec::operations() {
+        let id_lexi = BenchmarkId::new(operation.function(Ordering::Lexi), operation.id());
+        let id_cami = BenchmarkId::new(operation.function(Ordering::Cami), operation.id());
+        match operation {
+            Operation::SortStable | Operation::SortUnstable => {
+                let stable_sort = operation == Operation::SortStable;
+                group.bench_function(id_lexi, |b| {
+                    b.iter(|| {
+                        col::lexi_indicated::<SpecOut<Spec>, SpecOutColl<Spec>>(
+                            hint::black_box(out),
+                            stable_sort,
+                        )
+                    })
+                });
+                group.bench_function(id_cami, |b| {
+                    b.iter(|| {
+                        col::cami_indicated::<SpecOut<Spec>, SpecOutCollCami<Spec>>(
+                            hint::black_box(out),
+                            stable_sort,
+                        )
+                    })
+                });
+            }
+            Operation::BinSearch => {
+                // Sorted collections. They're NOT a result of any benchmark above, because there
+                // is no guaranteed order of benchmarks.
+                let stable_sort = Spec::is_supported(Operation::SortStable);
+                let sorted_lexi =
+                    col::lexi_indicated::<SpecOut<Spec>, SpecOutColl<Spec>>(out, stable_sort);
+                let sorted_cami =
+                    col::cami_indicated::<SpecOut<Spec>, SpecOutCollCami<Spec>>(out, stable_sort);
 
-        let mut refs = vec![];
-        refs.extend(own_items.iter().map(core::convert::identity));
+                let out_cami = {
+                    let mut out_cami = Vec::with_capacity(out.len());
+                    out_cami.extend(out.iter().cloned().map(Cami::new));
+                    out_cami
+                };
 
-        let id_string = format!(
-            "{} items, each len max {MAX_ITEM_LEN}.{}",
-            own_items.len(),
-            generate_id_postfix(id_state)
-        );
-        if false {
-            let mut sorted_lexi = OutCollectionType::with_capacity(1);
-            group.bench_with_input(
-                BenchmarkId::new("std sort lexi.          ", id_string.clone()),
-                hint::black_box(&unsorted_items),
-                |b, unsorted_items| {
+                group.bench_function(id_lexi, |b| {
                     b.iter(|| {
-                        //sorted_lexi = hint::black_box(unsorted_items.clone()); @TODO ^^^-->
-                        // .clone()  \----> change to:
-                        //
-                        // .sorted_lexi.extend( it().map(|it_ref| it_ref.clone()))
-                        sorted_lexi.clear();
-                        sorted_lexi.extend(unsorted_items.iter().cloned());
-
-                        //sorted_lexi.sort_by(<OutItemIndicatorImpl as
-                        //OutItemIndicator>::OutItemLifetimedImpl::cmp);
-                        sorted_lexi.sort();
+                        let sorted = hint::black_box(&sorted_lexi);
+                        for item in hint::black_box(out.iter()) {
+                            assert!(hint::black_box(sorted.binary_search(item)));
+                        }
                     })
-                },
-            );
-            purge_cache();
-            group.bench_with_input(
-                BenchmarkId::new("std bin search (lexi)   ", id_string.clone()),
-                hint::black_box(&unsorted_items),
-                |b, unsorted_items| {
+                });
+                group.bench_function(id_cami, |b| {
                     b.iter(|| {
-                        let sorted = hint::black_box(&sorted_lexi);
-                        for item in hint::black_box(unsorted_items.iter()) {
-                            assert!(hint::black_box(sorted.binary_search(&item)));
+                        let sorted = hint::black_box(&sorted_cami);
+                        for item in hint::black_box(out_cami.iter()) {
+                            assert!(hint::black_box(sorted.binary_search(item)));
                         }
                     })
-                },
-            );
-        }
-        {
-            purge_cache();
-            // @TODO cfg
-            //
-            //#[cfg(not(feature = "transmute"))]
-            let unsorted_items = {
-                let mut unsorted_items_cami = Vec::with_capacity(unsorted_items.len());
-                unsorted_items_cami.extend(
-                    unsorted_items
-                        .iter()
-                        .map(|v| Cami::<OutType>::new(v.clone())),
-                );
-                unsorted_items_cami
-            };
-
-            let mut sorted_non_lexi = Vec::new();
-            group.bench_with_input(
-                BenchmarkId::new("std sort non-lexi.      ", id_string.clone()),
-                hint::black_box(&unsorted_items),
-                |b, unsorted_items| {
+                });
+            }
+            Operation::PushPop => {
+                let workload = Spec::push_pop_workload(own_and_out);
+                group.bench_function(id_lexi, |b| {
                     b.iter(|| {
-                        // @TODO cfg
-                        //
-                        /*
-                        #[cfg(feature = "transmute")]
-                        let _ = {
-                            // @TODO replace .clone() by: Vec::with_capacity(), .iter() -> extend ->
-                            // .into_vec_cami()
-                            let unsorted_items = (*unsorted_items).clone();
-
-                            // @TODO TODO sorted_non_lexi =
-                            //hint::black_box(unsorted_items).into_vec().into_vec_cami();
-                        };
-                        */
-                        // @TODO cfg
-                        //
-                        // #[cfg(not(feature = "transmute"))]
-                        let _ = {
-                            sorted_non_lexi = hint::black_box(unsorted_items.clone());
-                        };
-                        sorted_non_lexi.sort();
+                        col::push_pop_lexi_indicated::<SpecOut<Spec>, SpecOutColl<Spec>>(
+                            hint::black_box(out),
+                            hint::black_box(&workload),
+                        )
                     })
-                },
-            );
-            purge_cache();
-            group.bench_with_input(
-                BenchmarkId::new("std bin search (non-lexi)", id_string),
-                hint::black_box(&unsorted_items),
-                //hint::black_box( unsorted_items.into_ref_vec_cami() ),
-                |b, unsorted_items| {
+                });
+                group.bench_function(id_cami, |b| {
                     b.iter(|| {
-                        let sorted = hint::black_box(&sorted_non_lexi);
-                        for item in hint::black_box(unsorted_items.iter()) {
-                            // @TODO cfg
-                            //
-                            /*#[cfg(feature = "transmute")]
-                            let _ = {
-                                hint::black_box(sorted.binary_search(item.into_ref_cami()))
-                                    .unwrap();
-                            };*/
-                            // @TODO cfg
-                            //
-                            //#[cfg(not(feature = "transmute"))]
-                            let _ = {
-                                hint::black_box(sorted.binary_search(item)).unwrap();
-                            };
-                        }
+                        col::push_pop_cami_indicated::<SpecOut<Spec>, SpecOutCollCami<Spec>>(
+                            hint::black_box(out),
+                            hint::black_box(&workload),
+                        )
                     })
-                },
-            );
+                });
+            }
+        }
+    }
+}
+
+/// Benchmark all [MapOperation]s of `Spec`, both with lexicographic and [Cami] keys. Group name,
+/// function names & IDs are like in [bench_spec] (see [MapOperation::function] and
+/// [MapOperation::id]).
+pub fn bench_map_spec<Spec: MapSpec>(critty: &mut Criterion)
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    for own_and_out in own_and_outs::<Spec>() {
+        let out = own_and_out.out;
+        let workload = Spec::map_workload(&own_and_out);
+        let group_name = Spec::group_name_for(&own_and_out);
+        println!("{group_name}: {}", DatasetStats::new(out));
+        let mut group = critty.benchmark_group(group_name);
+
+        let map_lexi = col::map_build_lexi_indicated::<SpecOut<Spec>, SpecOutMap<Spec>>(
+            out,
+            &workload.payloads,
+        );
+        let map_cami = col::map_build_cami_indicated::<SpecOut<Spec>, SpecOutMapCami<Spec>>(
+            out,
+            &workload.payloads,
+        );
+        let out_cami = {
+            let mut out_cami = Vec::with_capacity(out.len());
+            out_cami.extend(out.iter().cloned().map(Cami::new));
+            out_cami
+        };
+
+        for operation in MapOperation::ALL {
+            let id_lexi = BenchmarkId::new(operation.function(Ordering::Lexi), operation.id());
+            let id_cami = BenchmarkId::new(operation.function(Ordering::Cami), operation.id());
+            match operation {
+                MapOperation::Build => {
+                    group.bench_function(id_lexi, |b| {
+                        b.iter(|| {
+                            col::map_build_lexi_indicated::<SpecOut<Spec>, SpecOutMap<Spec>>(
+                                hint::black_box(out),
+                                hint::black_box(&workload.payloads),
+                            )
+                        })
+                    });
+                    group.bench_function(id_cami, |b| {
+                        b.iter(|| {
+                            col::map_build_cami_indicated::<SpecOut<Spec>, SpecOutMapCami<Spec>>(
+                                hint::black_box(out),
+                                hint::black_box(&workload.payloads),
+                            )
+                        })
+                    });
+                }
+                MapOperation::Lookup => {
+                    group.bench_function(id_lexi, |b| {
+                        b.iter(|| col::map_lookup(hint::black_box(&map_lexi), hint::black_box(out)))
+                    });
+                    group.bench_function(id_cami, |b| {
+                        b.iter(|| {
+                            col::map_lookup(hint::black_box(&map_cami), hint::black_box(&out_cami))
+                        })
+                    });
+                }
+                MapOperation::Range => {
+                    group.bench_function(id_lexi, |b| {
+                        b.iter(|| {
+                            col::map_range(
+                                hint::black_box(&map_lexi),
+                                hint::black_box(out),
+                                hint::black_box(&workload.range_queries),
+                            )
+                        })
+                    });
+                    group.bench_function(id_cami, |b| {
+                        b.iter(|| {
+                            col::map_range(
+                                hint::black_box(&map_cami),
+                                hint::black_box(&out_cami),
+                                hint::black_box(&workload.range_queries),
+                            )
+                        })
+                    });
+                }
+            }
         }
-        let _refs = refs;
+        group.finish();
     }
-    group.finish();
 }
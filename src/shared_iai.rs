@@ -1,11 +1,18 @@
 // This file is used from various benches, and not all of them use all functionality from here. So,
 // some items have `#[allow(unused)]`.
 use super::outish::*;
+use crate::data::{MapWorkload, OwnAndOut};
+use crate::dataset_stats::DatasetStats;
+use crate::fingerprint::ItemBytes;
+use crate::rnd::{
+    self, Random, RND_SEEDS_ENV, RND_SEED_AUTO_ENV, RND_SEED_COUNT_ENV, RND_SEED_DEC_ENV,
+    RND_SEED_HEX_ENV,
+};
+use crate::spec::{BenchSpec, MapSpec, SpecOut, SpecOutMap, SpecOutMapCami};
 use alloc::collections::BTreeSet;
 use cami::prelude::*;
 use core::marker::PhantomData;
 use core::ops::RangeBounds;
-use fastrand::Rng;
 use std::hint;
 use std::str::FromStr;
 
@@ -39,7 +46,8 @@ pub struct DataOut<
     /// Cami ordering (potentially non-lexicographic)
     pub sorted_col_cami: OutCollectionCami,
 
-    // @TODO remove:
+    /// The same items as `unsorted_vec_classic`, but wrapped in [Cami]. Used for looking up items
+    /// in `sorted_col_cami`.
     pub unsorted_vec_cami: Vec<Cami<OutType>>,
     // @TODO remove:
     /// Cami sorting (potentially non-lexicographic)
@@ -68,7 +76,7 @@ pub fn data_out<
     OutCollectionType: OutCollection<'own, OutType>,
     OutCollectionCami: OutCollection<'own, Cami<OutType>>,
 >(
-    own_items: &'own Vec<OwnType>,
+    own_items: &'own [OwnType],
     generate_out_item: impl Fn(&'own OwnType) -> OutType,
 ) -> DataOut<'own, OutType, OutCollectionType, OutCollectionCami> {
     let unsorted_vec_classic = {
@@ -84,7 +92,18 @@ pub fn data_out<
         }
         unsorted
     };
+    data_out_unsorted(unsorted_vec_classic)
+}
 
+/// Like [data_out], but from already generated (and, if need be, deduplicated) "out" items.
+pub fn data_out_unsorted<
+    'own,
+    OutType: Out + 'own,
+    OutCollectionType: OutCollection<'own, OutType>,
+    OutCollectionCami: OutCollection<'own, Cami<OutType>>,
+>(
+    unsorted_vec_classic: Vec<OutType>,
+) -> DataOut<'own, OutType, OutCollectionType, OutCollectionCami> {
     let unsorted_col_classic = {
         let mut unsorted = OutCollectionType::with_capacity(unsorted_vec_classic.len());
         unsorted.extend(unsorted_vec_classic.iter().cloned());
@@ -95,7 +114,11 @@ pub fn data_out<
 
     let sorted_col_classic = {
         let mut sorted = unsorted_col_classic.clone();
-        sorted.sort();
+        if OutCollectionType::HAS_SORT {
+            sorted.sort();
+        } else {
+            sorted.sort_unstable();
+        }
         sorted
     };
 
@@ -124,7 +147,11 @@ pub fn data_out<
 
     let sorted_col_cami = {
         let mut sorted = unsorted_col_cami.clone();
-        sorted.sort();
+        if OutCollectionCami::HAS_SORT {
+            sorted.sort();
+        } else {
+            sorted.sort_unstable();
+        }
         sorted
     };
 
@@ -149,8 +176,458 @@ pub fn data_out_indicated<
     OutIndicatorIndicatorImpl: OutIndicatorIndicator,
     OutCollectionIndicatorImpl: OutCollectionIndicator,
 >(
-    own_items: &'static Vec<OwnType>,
+    own_items: &'static [OwnType],
     generate_out_item: impl Fn(&'static OwnType) -> OutRetriever<'_, OutIndicatorIndicatorImpl, SubType>,
 ) -> DataOutIndicated<SubType, OutIndicatorIndicatorImpl, OutCollectionIndicatorImpl> {
     data_out(own_items, generate_out_item)
 }
+
+pub type DataOutSpec<Spec> = DataOutIndicated<
+    <Spec as BenchSpec>::Sub,
+    <Spec as BenchSpec>::OutIndicatorIndicatorImpl,
+    <Spec as BenchSpec>::OutCollectionIndicatorImpl,
+>;
+
+/// [BenchSpec::own_and_out], for setup of `iai-callgrind` benchmarks. `iai-callgrind` runs each
+/// benchmark in a separate process, hence this [panic]s
+/// - if [rnd::seeds_from_env] gives seeds (it can't aggregate across seeds - run the bench once per
+///   seed instead), or
+/// - if [rnd::seed_is_auto] (each benchmark would get a different seed, so lexicographic & [Cami]
+///   benchmarks would run on different data).
+pub fn own_and_out<Spec: BenchSpec>() -> OwnAndOut<Spec::Own, SpecOut<Spec>>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    if rnd::seeds_from_env(<Spec::Rnd as Random>::SEED_WORDS).is_some() {
+        panic!(
+            "Environment variables {RND_SEEDS_ENV} and {RND_SEED_COUNT_ENV} are not supported by iai-callgrind benches. Run the bench once per seed (with {RND_SEED_DEC_ENV} or {RND_SEED_HEX_ENV}) instead."
+        );
+    }
+    if rnd::seed_is_auto() {
+        panic!(
+            "Environment variable {RND_SEED_AUTO_ENV} is not supported by iai-callgrind benches (each benchmark would get a different seed). Set {RND_SEED_DEC_ENV} or {RND_SEED_HEX_ENV} instead."
+        );
+    }
+    Spec::own_and_out()
+}
+
+/// Print (to `stdout`) `group` (the `iai-callgrind` group name), [BenchSpec::group_name_for] the
+/// data from [own_and_out] and [DatasetStats] of its "out" items. For `setup` of a
+/// `library_benchmark_group!`, so that it's printed once per group.
+pub fn print_group_data<Spec: BenchSpec>(group: &str)
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    let own_and_out = own_and_out::<Spec>();
+    println!(
+        "{group}: {}: {}",
+        Spec::group_name_for(&own_and_out),
+        DatasetStats::new(own_and_out.out)
+    );
+}
+
+/// [DataOut] for "out" items from [own_and_out].
+pub fn data_out_for_spec<Spec: BenchSpec>() -> DataOutSpec<Spec>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    data_out_unsorted(own_and_out::<Spec>().out.to_vec())
+}
+
+/// Setup of map benchmarks (see [crate::iai_map_bench_matrix]): keys (lexicographic, or [Cami]),
+/// a map of them, and [MapSpec::map_workload].
+pub struct MapDataOut<K, OutMapType> {
+    pub keys: Vec<K>,
+    pub map: OutMapType,
+    pub workload: MapWorkload,
+}
+
+pub type MapDataOutLexi<Spec> = MapDataOut<SpecOut<Spec>, SpecOutMap<Spec>>;
+pub type MapDataOutCami<Spec> = MapDataOut<Cami<SpecOut<Spec>>, SpecOutMapCami<Spec>>;
+
+/// [MapDataOut] with lexicographic keys, for "out" items from [own_and_out].
+pub fn map_data_out_lexi<Spec: MapSpec>() -> MapDataOutLexi<Spec>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    let own_and_out = own_and_out::<Spec>();
+    let workload = Spec::map_workload(&own_and_out);
+    MapDataOut {
+        keys: own_and_out.out.to_vec(),
+        map: crate::col::map_build_lexi_indicated::<SpecOut<Spec>, SpecOutMap<Spec>>(
+            own_and_out.out,
+            &workload.payloads,
+        ),
+        workload,
+    }
+}
+
+/// Like [map_data_out_lexi], but with [Cami] keys.
+pub fn map_data_out_cami<Spec: MapSpec>() -> MapDataOutCami<Spec>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    let own_and_out = own_and_out::<Spec>();
+    let workload = Spec::map_workload(&own_and_out);
+    MapDataOut {
+        keys: own_and_out.out.iter().cloned().map(Cami::new).collect(),
+        map: crate::col::map_build_cami_indicated::<SpecOut<Spec>, SpecOutMapCami<Spec>>(
+            own_and_out.out,
+            &workload.payloads,
+        ),
+        workload,
+    }
+}
+//------
+
+/// Look up (binary search) each item of [DataOut::unsorted_vec_classic] in
+/// [DataOut::sorted_col_classic].
+pub fn bin_search_lexi<
+    'own,
+    OutType: Out + 'own,
+    OutCollectionClassic: OutCollection<'own, OutType>,
+    OutCollectionCami: OutCollection<'own, Cami<OutType>>,
+>(
+    data_out: &DataOut<'own, OutType, OutCollectionClassic, OutCollectionCami>,
+) {
+    let sorted = hint::black_box(&data_out.sorted_col_classic);
+    for item in hint::black_box(&data_out.unsorted_vec_classic).iter() {
+        assert!(hint::black_box(sorted.binary_search(item)));
+    }
+}
+
+/// Look up (binary search) each item of [DataOut::unsorted_vec_cami] in
+/// [DataOut::sorted_col_cami].
+pub fn bin_search_cami<
+    'own,
+    OutType: Out + 'own,
+    OutCollectionClassic: OutCollection<'own, OutType>,
+    OutCollectionCami: OutCollection<'own, Cami<OutType>>,
+>(
+    data_out: &DataOut<'own, OutType, OutCollectionClassic, OutCollectionCami>,
+) {
+    let sorted = hint::black_box(&data_out.sorted_col_cami);
+    for item in hint::black_box(&data_out.unsorted_vec_cami).iter() {
+        assert!(hint::black_box(sorted.binary_search(item)));
+    }
+}
+
+/// Look up each item of `out` in `hashed` (a hash-based collection - see
+/// [crate::spec::HashSpec]).
+pub fn hash_lookup<'own, OutType: Out + 'own, OutCollectionHash: OutCollection<'own, OutType>>(
+    out: &[OutType],
+    hashed: &OutCollectionHash,
+) {
+    let hashed = hint::black_box(hashed);
+    for item in hint::black_box(out).iter() {
+        assert!(hint::black_box(hashed.binary_search(item)));
+    }
+}
+//------
+
+/// Generate the whole `iai-callgrind` benchmark matrix for a [BenchSpec]:
+/// - `sort_lexi` & `sort_cami`, each with one benchmark per listed `sorts` ID (`stable` and/or
+///   `unstable`), and
+/// - `bin_search_lexi` & `bin_search_cami` (with benchmark ID `bin_search`), unless `push_pop`,
+///
+/// plus a `library_benchmark_group!` named per `group`, which compares them by ID (and whose
+/// `setup` prints the data's fingerprint & statistics - see
+/// [crate::shared_iai::print_group_data]). The function names and IDs are
+/// [crate::spec::Operation::function] and [crate::spec::Operation::id]. You still need to invoke
+/// `main!` (from `iai_callgrind`) yourself.
+///
+/// List only `sorts` that the spec supports (see [BenchSpec::is_supported]). Otherwise this fails
+/// to compile.
+///
+/// For priority queues, add `push_pop` (after `sorts`): that adds `push_pop_lexi` &
+/// `push_pop_cami` (with benchmark ID `push_pop`), running [BenchSpec::push_pop_workload], instead
+/// of `bin_search_lexi` & `bin_search_cami` (a priority queue has no order to search by - see
+/// [OutCollection::HAS_BINARY_SEARCH]). Again, this fails to compile if the spec doesn't support
+/// [crate::spec::Operation::PushPop].
+///
+/// For a [crate::spec::HashSpec] (with `std` feature), add `hash_lookup` (after `sorts`): that
+/// adds `hash_lookup` (with benchmark ID `bin_search`, so that it's compared with binary search) -
+/// see [crate::spec::HASH_LOOKUP_FUNCTION].
+///
+/// Example:
+/// ```ignore
+/// cami_benches::iai_bench_matrix! {
+///     group: bench_group,
+///     spec: cami_benches::spec::AllocVecU8,
+///     sorts: [stable, unstable],
+/// }
+/// main!(library_benchmark_groups = bench_group);
+/// ```
+#[macro_export]
+macro_rules! iai_bench_matrix {
+    (@operation stable) => {
+        $crate::spec::Operation::SortStable
+    };
+    (@operation unstable) => {
+        $crate::spec::Operation::SortUnstable
+    };
+    (
+        group: $group:ident,
+        spec: $spec:ty,
+        sorts: [$($sort:ident),+ $(,)?] $(,)?
+    ) => {
+        $crate::iai_bench_matrix!(
+            @matrix $group, $spec, [$($sort),+], [bin_search], [bin_search_lexi, bin_search_cami]
+        );
+    };
+    (
+        group: $group:ident,
+        spec: $spec:ty,
+        sorts: [$($sort:ident),+ $(,)?],
+        push_pop $(,)?
+    ) => {
+        $crate::iai_bench_matrix!(@matrix $group, $spec, [$($sort),+], [], [push_pop_lexi, push_pop_cami]);
+
+        const _: () = ::core::assert!(
+            $crate::spec::Operation::PushPop.is_supported::<OutType, OutColLexi>(),
+            "Push & pop not supported by the collection."
+        );
+
+        fn push_pop_setup() -> (OutTypeRef, ::std::vec::Vec<$crate::data::PushPop>) {
+            let own_and_out = $crate::shared_iai::own_and_out::<$spec>();
+            let workload = <$spec as $crate::spec::BenchSpec>::push_pop_workload(&own_and_out);
+            (own_and_out.out, workload)
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::push_pop(push_pop_setup())]
+        fn push_pop_lexi(setup: (OutTypeRef, ::std::vec::Vec<$crate::data::PushPop>)) -> OutColLexi {
+            let (out, workload) = setup;
+            ::core::hint::black_box($crate::col::push_pop_lexi_indicated::<OutType, OutColLexi>(
+                out, &workload,
+            ))
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::push_pop(push_pop_setup())]
+        fn push_pop_cami(setup: (OutTypeRef, ::std::vec::Vec<$crate::data::PushPop>)) -> OutColCami {
+            let (out, workload) = setup;
+            ::core::hint::black_box($crate::col::push_pop_cami_indicated::<OutType, OutColCami>(
+                out, &workload,
+            ))
+        }
+    };
+    (
+        group: $group:ident,
+        spec: $spec:ty,
+        sorts: [$($sort:ident),+ $(,)?],
+        hash_lookup $(,)?
+    ) => {
+        $crate::iai_bench_matrix!(
+            @matrix $group, $spec, [$($sort),+], [bin_search],
+            [bin_search_lexi, bin_search_cami, hash_lookup]
+        );
+
+        type OutColHash = $crate::spec::SpecOutHash<$spec>;
+
+        fn hash_setup() -> (OutTypeRef, OutColHash) {
+            let out = out();
+            (out, $crate::col::hash_indicated::<OutType, OutColHash>(out))
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::bin_search(hash_setup())]
+        fn hash_lookup(setup: (OutTypeRef, OutColHash)) -> OutColHash {
+            let (out, hashed) = setup;
+            $crate::shared_iai::hash_lookup(out, &hashed);
+            hashed
+        }
+    };
+    (
+        @matrix $group:ident, $spec:ty, [$($sort:ident),+], [$($bin_search:ident)?],
+        [$($extra:ident),*]
+    ) => {
+        type OutType = $crate::spec::SpecOut<$spec>;
+        type OutTypeRef = &'static [OutType];
+        type OutColLexi = $crate::spec::SpecOutColl<$spec>;
+        type OutColCami = $crate::spec::SpecOutCollCami<$spec>;
+
+        $(
+            const _: () = ::core::assert!(
+                $crate::iai_bench_matrix!(@operation $sort).is_supported::<OutType, OutColLexi>(),
+                "Sort not supported by the collection."
+            );
+        )+
+
+        fn out() -> OutTypeRef {
+            $crate::shared_iai::own_and_out::<$spec>().out
+        }
+
+        fn group_setup() {
+            $crate::shared_iai::print_group_data::<$spec>(::core::stringify!($group));
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        $( #[bench::$sort(out(), $crate::iai_bench_matrix!(@operation $sort))] )+
+        fn sort_lexi(out: OutTypeRef, operation: $crate::spec::Operation) -> OutColLexi {
+            let stable_sort = operation == $crate::spec::Operation::SortStable;
+            ::core::hint::black_box($crate::col::lexi_indicated::<OutType, OutColLexi>(
+                out,
+                stable_sort,
+            ))
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        $( #[bench::$sort(out(), $crate::iai_bench_matrix!(@operation $sort))] )+
+        fn sort_cami(out: OutTypeRef, operation: $crate::spec::Operation) -> OutColCami {
+            let stable_sort = operation == $crate::spec::Operation::SortStable;
+            ::core::hint::black_box($crate::col::cami_indicated::<OutType, OutColCami>(
+                out,
+                stable_sort,
+            ))
+        }
+
+        $(
+            type DataOutType = $crate::shared_iai::DataOutSpec<$spec>;
+
+            const _: () = ::core::assert!(
+                $crate::spec::Operation::BinSearch.is_supported::<OutType, OutColLexi>(),
+                "Binary search not supported by the collection."
+            );
+
+            fn data_out() -> DataOutType {
+                $crate::shared_iai::data_out_for_spec::<$spec>()
+            }
+
+            #[::iai_callgrind::library_benchmark]
+            #[bench::$bin_search(data_out())]
+            fn bin_search_lexi(data_out: DataOutType) -> DataOutType {
+                $crate::shared_iai::bin_search_lexi(&data_out);
+                data_out
+            }
+
+            #[::iai_callgrind::library_benchmark]
+            #[bench::$bin_search(data_out())]
+            fn bin_search_cami(data_out: DataOutType) -> DataOutType {
+                $crate::shared_iai::bin_search_cami(&data_out);
+                data_out
+            }
+        )?
+
+        ::iai_callgrind::library_benchmark_group!(
+            name = $group;
+            config = ::iai_callgrind::LibraryBenchmarkConfig::default().env_clear(false);
+            compare_by_id = true;
+            setup = group_setup();
+            benchmarks = sort_lexi, sort_cami $(, $extra)*
+        );
+    };
+}
+
+/// Generate `iai-callgrind` benchmarks of all [crate::spec::MapOperation]s for a
+/// [crate::spec::MapSpec]: `map_build_lexi` & `map_build_cami` (with benchmark ID `map_build`),
+/// `map_lookup_...` (`map_lookup`) and `map_range_...` (`map_range`), plus a
+/// `library_benchmark_group!` named per `group`, which compares them by ID (and whose `setup`
+/// prints the data's fingerprint & statistics - see [crate::shared_iai::print_group_data]). You
+/// still need to invoke `main!` (from `iai_callgrind`) yourself.
+///
+/// Example:
+/// ```ignore
+/// cami_benches::iai_map_bench_matrix! {
+///     group: bench_group,
+///     spec: cami_benches::spec::AllocBTreeMapStr,
+/// }
+/// main!(library_benchmark_groups = bench_group);
+/// ```
+#[macro_export]
+macro_rules! iai_map_bench_matrix {
+    (
+        group: $group:ident,
+        spec: $spec:ty $(,)?
+    ) => {
+        type OutType = $crate::spec::SpecOut<$spec>;
+        type OutTypeRef = &'static [OutType];
+        type OutMapLexi = $crate::spec::SpecOutMap<$spec>;
+        type OutMapCami = $crate::spec::SpecOutMapCami<$spec>;
+        type MapDataLexi = $crate::shared_iai::MapDataOutLexi<$spec>;
+        type MapDataCami = $crate::shared_iai::MapDataOutCami<$spec>;
+
+        fn map_build_setup() -> (OutTypeRef, $crate::data::MapWorkload) {
+            let own_and_out = $crate::shared_iai::own_and_out::<$spec>();
+            let workload = <$spec as $crate::spec::MapSpec>::map_workload(&own_and_out);
+            (own_and_out.out, workload)
+        }
+
+        fn map_data_lexi() -> MapDataLexi {
+            $crate::shared_iai::map_data_out_lexi::<$spec>()
+        }
+
+        fn map_data_cami() -> MapDataCami {
+            $crate::shared_iai::map_data_out_cami::<$spec>()
+        }
+
+        fn group_setup() {
+            $crate::shared_iai::print_group_data::<$spec>(::core::stringify!($group));
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::map_build(map_build_setup())]
+        fn map_build_lexi(setup: (OutTypeRef, $crate::data::MapWorkload)) -> OutMapLexi {
+            let (out, workload) = setup;
+            ::core::hint::black_box($crate::col::map_build_lexi_indicated::<OutType, OutMapLexi>(
+                out,
+                &workload.payloads,
+            ))
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::map_build(map_build_setup())]
+        fn map_build_cami(setup: (OutTypeRef, $crate::data::MapWorkload)) -> OutMapCami {
+            let (out, workload) = setup;
+            ::core::hint::black_box($crate::col::map_build_cami_indicated::<OutType, OutMapCami>(
+                out,
+                &workload.payloads,
+            ))
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::map_lookup(map_data_lexi())]
+        fn map_lookup_lexi(data: MapDataLexi) -> MapDataLexi {
+            $crate::col::map_lookup(&data.map, &data.keys);
+            data
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::map_lookup(map_data_cami())]
+        fn map_lookup_cami(data: MapDataCami) -> MapDataCami {
+            $crate::col::map_lookup(&data.map, &data.keys);
+            data
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::map_range(map_data_lexi())]
+        fn map_range_lexi(data: MapDataLexi) -> MapDataLexi {
+            ::core::hint::black_box($crate::col::map_range(
+                &data.map,
+                &data.keys,
+                &data.workload.range_queries,
+            ));
+            data
+        }
+
+        #[::iai_callgrind::library_benchmark]
+        #[bench::map_range(map_data_cami())]
+        fn map_range_cami(data: MapDataCami) -> MapDataCami {
+            ::core::hint::black_box($crate::col::map_range(
+                &data.map,
+                &data.keys,
+                &data.workload.range_queries,
+            ));
+            data
+        }
+
+        ::iai_callgrind::library_benchmark_group!(
+            name = $group;
+            config = ::iai_callgrind::LibraryBenchmarkConfig::default().env_clear(false);
+            compare_by_id = true;
+            setup = group_setup();
+            benchmarks = map_build_lexi, map_build_cami, map_lookup_lexi, map_lookup_cami,
+                map_range_lexi, map_range_cami
+        );
+    };
+}
@@ -0,0 +1,419 @@
+//! Minimal wall-clock harness, using [std::time::Instant] only (no extra dependencies). It's for a
+//! quick local lexi vs. [Cami] comparison. Callgrind is slow on large datasets, and `Criterion` is
+//! optional.
+//!
+//! Each benchmark is
+//! 1. warmed up for [WallConfig::warm_up] (which also estimates the time per iteration),
+//! 2. measured in [WallConfig::samples] samples, each running as many iterations as fit in
+//!    [WallConfig::sample_time] (at least one), and
+//! 3. summarized by [Stats]: median & MAD (median absolute deviation) of time per iteration, after
+//!    rejecting outlier samples.
+use crate::col;
+use crate::data::OwnAndOut;
+use crate::dataset_stats::DatasetStats;
+use crate::fingerprint::ItemBytes;
+use crate::outish::OutCollection;
+use crate::rnd::{self, Random, RND_SEED_HEX_ENV};
+use crate::spec::{BenchSpec, Operation, Ordering, SpecOut, SpecOutColl, SpecOutCollCami};
+use cami::Cami;
+use core::hint;
+use core::time::Duration;
+use std::time::Instant;
+
+#[derive(Clone, Debug)]
+pub struct WallConfig {
+    pub warm_up: Duration,
+    /// Number of samples (before rejecting outliers).
+    pub samples: usize,
+    /// Target duration of one sample.
+    pub sample_time: Duration,
+    /// A sample is an outlier (and it gets rejected) if it's further from the median than this
+    /// many MADs.
+    pub outlier_mads: f64,
+}
+
+impl Default for WallConfig {
+    /// Similar to [crate::shared_criterion::criterion_config] - short, because this functionality
+    /// is simple.
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(100),
+            samples: 30,
+            sample_time: Duration::from_millis(30),
+            outlier_mads: 3.0,
+        }
+    }
+}
+
+/// Statistics of time per iteration, in nanoseconds. All fields except for `rejected` are over the
+/// kept (non-outlier) samples only.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub median: f64,
+    /// Median absolute deviation (from `median`).
+    pub mad: f64,
+    pub min: f64,
+    pub max: f64,
+    pub kept: usize,
+    pub rejected: usize,
+}
+
+impl Stats {
+    /// Reject samples further than `outlier_mads` MADs from the median, and then summarize the
+    /// rest. If MAD is zero, no sample gets rejected.
+    ///
+    /// `samples` must not be empty.
+    pub fn from_samples(samples: &[f64], outlier_mads: f64) -> Self {
+        assert!(!samples.is_empty());
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let median = median(&sorted);
+        let mad = mad(&sorted, median);
+        if mad > 0.0 {
+            sorted.retain(|&sample| (sample - median).abs() <= outlier_mads * mad);
+        }
+
+        let median = self::median(&sorted);
+        Self {
+            median,
+            mad: self::mad(&sorted, median),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            kept: sorted.len(),
+            rejected: samples.len() - sorted.len(),
+        }
+    }
+}
+
+/// Param `sorted` must be sorted, and not empty.
+fn median(sorted: &[f64]) -> f64 {
+    let len = sorted.len();
+    if len % 2 == 1 {
+        sorted[len / 2]
+    } else {
+        (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+    }
+}
+
+/// Median absolute deviation. Param `sorted` must be sorted, and not empty.
+fn mad(sorted: &[f64], median: f64) -> f64 {
+    let mut deviations = Vec::with_capacity(sorted.len());
+    deviations.extend(sorted.iter().map(|sample| (sample - median).abs()));
+    deviations.sort_by(f64::total_cmp);
+    self::median(&deviations)
+}
+
+/// Measure `routine`. Its results are dropped only after each sample (so that dropping is not
+/// measured).
+pub fn measure<R>(config: &WallConfig, mut routine: impl FnMut() -> R) -> Stats {
+    let warm_up_start = Instant::now();
+    let mut warm_up_iterations = 0u64;
+    loop {
+        hint::black_box(routine());
+        warm_up_iterations += 1;
+        if warm_up_start.elapsed() >= config.warm_up {
+            break;
+        }
+    }
+    let nanos_per_iteration = warm_up_start.elapsed().as_nanos() as f64 / warm_up_iterations as f64;
+    let iterations = ((config.sample_time.as_nanos() as f64 / nanos_per_iteration) as usize).max(1);
+
+    let mut samples = Vec::with_capacity(config.samples);
+    let mut results = Vec::with_capacity(iterations);
+    for _ in 0..config.samples.max(1) {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            results.push(hint::black_box(routine()));
+        }
+        let elapsed = start.elapsed();
+        results.clear();
+        samples.push(elapsed.as_nanos() as f64 / iterations as f64);
+    }
+    Stats::from_samples(&samples, config.outlier_mads)
+}
+
+/// One result of [bench_spec].
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    /// `<group>/<function>/<id>`, the same as in [crate::shared_criterion::bench_spec].
+    pub id: String,
+    pub operation: Operation,
+    pub ordering: Ordering,
+    pub stats: Stats,
+}
+
+/// Benchmark all [BenchSpec::operations] of `Spec`, both lexicographic and [Cami], print the
+/// results (including cami/lexi ratio of medians) to `stdout` and return them. The data comes from
+/// [BenchSpec::own_and_out], and its [DatasetStats] get printed, too.
+pub fn bench_spec<Spec: BenchSpec>(config: &WallConfig) -> Vec<Measurement>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    let own_and_out = Spec::own_and_out();
+    let out = own_and_out.out;
+    let group_name = Spec::group_name_for(&own_and_out);
+    let fingerprint = own_and_out.fingerprint();
+    println!(
+        "{group_name}: {} items (from {} generated items of {} bytes in total)",
+        out.len(),
+        fingerprint.own_items,
+        fingerprint.own_bytes
+    );
+    println!("{group_name}: {}", DatasetStats::new(out));
+
+    let mut measurements = Vec::new();
+    for (operation, lexi, cami) in measure_spec::<Spec>(config, &own_and_out) {
+        println!(
+            "{group_name}/{}: cami/lexi median ratio {:.3}",
+            operation.id(),
+            cami.median / lexi.median
+        );
+        for (ordering, stats) in [(Ordering::Lexi, lexi), (Ordering::Cami, cami)] {
+            let id = measurement_id(&group_name, operation, ordering);
+            println!(
+                "  {id:<32} median {} ± {} (MAD), min {}, max {}, {} samples, {} outliers rejected",
+                format_nanos(stats.median),
+                format_nanos(stats.mad),
+                format_nanos(stats.min),
+                format_nanos(stats.max),
+                stats.kept,
+                stats.rejected
+            );
+            measurements.push(Measurement {
+                id,
+                operation,
+                ordering,
+                stats,
+            });
+        }
+    }
+    measurements
+}
+
+/// `<group>/<function>/<id>`, the same as in [crate::shared_criterion::bench_spec].
+fn measurement_id(group_name: &str, operation: Operation, ordering: Ordering) -> String {
+    format!(
+        "{group_name}/{}/{}",
+        operation.function(ordering),
+        operation.id()
+    )
+}
+
+/// Measure all [BenchSpec::operations] of `Spec` on "out" items of `own_and_out`: lexicographic &
+/// [Cami] [Stats] for each.
+fn measure_spec<Spec: BenchSpec>(
+    config: &WallConfig,
+    own_and_out: &OwnAndOut<Spec::Own, SpecOut<Spec>>,
+) -> Vec<(Operation, Stats, Stats)>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    let out = own_and_out.out;
+    let mut results = Vec::new();
+    for operation in Spec::operations() {
+        let (lexi, cami) = match operation {
+            Operation::SortStable | Operation::SortUnstable => {
+                let stable_sort = operation == Operation::SortStable;
+                let lexi = measure(config, || {
+                    col::lexi_indicated::<SpecOut<Spec>, SpecOutColl<Spec>>(
+                        hint::black_box(out),
+                        stable_sort,
+                    )
+                });
+                let cami = measure(config, || {
+                    col::cami_indicated::<SpecOut<Spec>, SpecOutCollCami<Spec>>(
+                        hint::black_box(out),
+                        stable_sort,
+                    )
+                });
+                (lexi, cami)
+            }
+            Operation::BinSearch => {
+                let stable_sort = Spec::is_supported(Operation::SortStable);
+                let sorted_lexi =
+                    col::lexi_indicated::<SpecOut<Spec>, SpecOutColl<Spec>>(out, stable_sort);
+                let sorted_cami =
+                    col::cami_indicated::<SpecOut<Spec>, SpecOutCollCami<Spec>>(out, stable_sort);
+
+                let out_cami = {
+                    let mut out_cami = Vec::with_capacity(out.len());
+                    out_cami.extend(out.iter().cloned().map(Cami::new));
+                    out_cami
+                };
+
+                let lexi = measure(config, || {
+                    let sorted = hint::black_box(&sorted_lexi);
+                    for item in hint::black_box(out.iter()) {
+                        assert!(hint::black_box(sorted.binary_search(item)));
+                    }
+                });
+                let cami = measure(config, || {
+                    let sorted = hint::black_box(&sorted_cami);
+                    for item in hint::black_box(out_cami.iter()) {
+                        assert!(hint::black_box(sorted.binary_search(item)));
+                    }
+                });
+                (lexi, cami)
+            }
+            Operation::PushPop => {
+                let workload = Spec::push_pop_workload(own_and_out);
+                let lexi = measure(config, || {
+                    col::push_pop_lexi_indicated::<SpecOut<Spec>, SpecOutColl<Spec>>(
+                        hint::black_box(out),
+                        hint::black_box(&workload),
+                    )
+                });
+                let cami = measure(config, || {
+                    col::push_pop_cami_indicated::<SpecOut<Spec>, SpecOutCollCami<Spec>>(
+                        hint::black_box(out),
+                        hint::black_box(&workload),
+                    )
+                });
+                (lexi, cami)
+            }
+        };
+        results.push((operation, lexi, cami));
+    }
+    results
+}
+
+/// Statistics of [Stats::median] (time per iteration, in nanoseconds) across seeds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeedsStats {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Population standard deviation.
+    pub stddev: f64,
+    /// Index (of the seed) of the median furthest from `mean`.
+    pub outlier: usize,
+}
+
+impl SeedsStats {
+    /// Param `medians` must not be empty.
+    pub fn from_medians(medians: &[f64]) -> Self {
+        assert!(!medians.is_empty());
+        let mean = medians.iter().sum::<f64>() / medians.len() as f64;
+        let variance = medians
+            .iter()
+            .map(|median| (median - mean) * (median - mean))
+            .sum::<f64>()
+            / medians.len() as f64;
+        let outlier = (0..medians.len())
+            .max_by(|&a, &b| {
+                (medians[a] - mean)
+                    .abs()
+                    .total_cmp(&(medians[b] - mean).abs())
+            })
+            .unwrap();
+        Self {
+            mean,
+            min: medians.iter().copied().fold(f64::INFINITY, f64::min),
+            max: medians.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            stddev: variance.sqrt(),
+            outlier,
+        }
+    }
+}
+
+/// One result of [bench_spec_seeds].
+#[derive(Clone, Debug)]
+pub struct SeedsMeasurement {
+    /// `<group>/<function>/<id>`, as in [Measurement::id].
+    pub id: String,
+    pub operation: Operation,
+    pub ordering: Ordering,
+    /// Master seed & [Stats] for that seed - in the same order as the seeds.
+    pub per_seed: Vec<(Vec<u64>, Stats)>,
+    pub stats: SeedsStats,
+}
+
+/// Like [bench_spec], but for each of `seeds` (see [rnd::seeds_from_env]). Print (to `stdout`)
+/// & return aggregated [SeedsStats] per benchmark ID, including the seed of the outlier (as
+/// `RND_SEED_HEX`, to reproduce it with [bench_spec]).
+pub fn bench_spec_seeds<Spec: BenchSpec>(
+    config: &WallConfig,
+    seeds: &[Vec<u64>],
+) -> Vec<SeedsMeasurement>
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    let group_name = Spec::group_name();
+    let mut measurements = Vec::<SeedsMeasurement>::new();
+    for seed in seeds {
+        let own_and_out = Spec::own_and_out_for_seed(seed);
+        let out = own_and_out.out;
+        println!(
+            "{group_name}: {} items for {RND_SEED_HEX_ENV}=\"{}\", {}",
+            out.len(),
+            rnd::format_seed_hex(seed),
+            own_and_out.fingerprint()
+        );
+        println!("{group_name}: {}", DatasetStats::new(out));
+        let mut index = 0;
+        for (operation, lexi, cami) in measure_spec::<Spec>(config, &own_and_out) {
+            for (ordering, stats) in [(Ordering::Lexi, lexi), (Ordering::Cami, cami)] {
+                if measurements.len() == index {
+                    measurements.push(SeedsMeasurement {
+                        id: measurement_id(&group_name, operation, ordering),
+                        operation,
+                        ordering,
+                        per_seed: Vec::with_capacity(seeds.len()),
+                        stats: SeedsStats::from_medians(&[stats.median]),
+                    });
+                }
+                measurements[index].per_seed.push((seed.clone(), stats));
+                index += 1;
+            }
+        }
+    }
+
+    for measurement in &mut measurements {
+        let medians = measurement
+            .per_seed
+            .iter()
+            .map(|(_, stats)| stats.median)
+            .collect::<Vec<_>>();
+        measurement.stats = SeedsStats::from_medians(&medians);
+        let stats = &measurement.stats;
+        println!(
+            "  {:<32} over {} seeds: mean {} ± {} (stddev), min {}, max {}, outlier {RND_SEED_HEX_ENV}=\"{}\"",
+            measurement.id,
+            medians.len(),
+            format_nanos(stats.mean),
+            format_nanos(stats.stddev),
+            format_nanos(stats.min),
+            format_nanos(stats.max),
+            rnd::format_seed_hex(&measurement.per_seed[stats.outlier].0)
+        );
+    }
+    measurements
+}
+
+/// [bench_spec_seeds] if [rnd::seeds_from_env] gives seeds, otherwise [bench_spec].
+pub fn bench_spec_per_env<Spec: BenchSpec>(config: &WallConfig)
+where
+    SpecOut<Spec>: ItemBytes,
+{
+    match rnd::seeds_from_env(<Spec::Rnd as Random>::SEED_WORDS) {
+        Some(seeds) => {
+            bench_spec_seeds::<Spec>(config, &seeds);
+        }
+        None => {
+            bench_spec::<Spec>(config);
+        }
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    if nanos >= 1e9 {
+        format!("{:.3} s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.3} ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.3} µs", nanos / 1e3)
+    } else {
+        format!("{nanos:.1} ns")
+    }
+}
@@ -0,0 +1,170 @@
+//! Binary snapshots of generated "own" items (see [crate::data::OwnAndOut]), so that repeated runs
+//! (and each of the processes that `iai-callgrind` starts) skip generation. Opt-in, per
+//! environment variable `DATA_SNAPSHOTS`.
+//!
+//! Format (all numbers are little-endian `u64`, unless stated otherwise):
+//! - [MAGIC], then [FORMAT_VERSION] (`u32`),
+//! - key (its length, then its UTF-8 bytes) - see [crate::spec::BenchSpec::snapshot_key],
+//! - [Fingerprint::hash] and [Fingerprint::out_items] of the data when it was generated,
+//! - number of items, then each item: its length, then its bytes (as per [ItemBytes]).
+//!
+//! A snapshot is read back as a whole (no memory mapping, so that we don't need any dependency).
+use crate::data;
+use crate::fingerprint::{self, Fingerprint, ItemBytes};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+pub const DATA_SNAPSHOTS_ENV: &str = "DATA_SNAPSHOTS";
+
+pub const MAGIC: &[u8; 8] = b"CAMISNAP";
+pub const FORMAT_VERSION: u32 = 1;
+
+/// "Own" item that can be restored from its [ItemBytes].
+pub trait OwnItem: ItemBytes + Sized {
+    fn from_item_bytes(bytes: &[u8]) -> Result<Self, String>;
+}
+
+impl OwnItem for u8 {
+    fn from_item_bytes(bytes: &[u8]) -> Result<Self, String> {
+        match bytes {
+            [byte] => Ok(*byte),
+            _ => Err(format!(
+                "A u8 item should have 1 byte, but it has {}.",
+                bytes.len()
+            )),
+        }
+    }
+}
+impl OwnItem for Vec<u8> {
+    fn from_item_bytes(bytes: &[u8]) -> Result<Self, String> {
+        Ok(bytes.to_vec())
+    }
+}
+impl OwnItem for String {
+    fn from_item_bytes(bytes: &[u8]) -> Result<Self, String> {
+        String::from_utf8(bytes.to_vec()).map_err(|err| format!("Invalid UTF-8: {err}"))
+    }
+}
+
+/// Whether snapshots are enabled: environment variable `DATA_SNAPSHOTS` is set (to anything but an
+/// empty string).
+pub fn enabled() -> bool {
+    std::env::var(DATA_SNAPSHOTS_ENV).is_ok_and(|snapshots| !snapshots.is_empty())
+}
+
+/// `target/data-snapshots/<name>-<hash of key>.snapshot` (see [data::target_dir]).
+pub fn path_for_key(name: &str, key: &str) -> PathBuf {
+    let key_hash = fingerprint::fnv_1a(fingerprint::FNV_OFFSET_BASIS, key.as_bytes());
+    data::target_dir()
+        .join("data-snapshots")
+        .join(format!("{name}-{key_hash:016x}.snapshot"))
+}
+
+/// Write a snapshot of `own` (with `fingerprint` of the data) to `path`. It's written to a
+/// temporary file first, and then renamed - so that concurrent readers never see a partial file.
+pub fn save<OwnType: OwnItem>(
+    path: &Path,
+    key: &str,
+    own: &[OwnType],
+    fingerprint: Fingerprint,
+) -> io::Result<()> {
+    let mut bytes = Vec::with_capacity(fingerprint.own_bytes + 8 * own.len() + key.len() + 64);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    push_len_prefixed(&mut bytes, key.as_bytes());
+    bytes.extend_from_slice(&fingerprint.hash.to_le_bytes());
+    bytes.extend_from_slice(&(fingerprint.out_items as u64).to_le_bytes());
+    bytes.extend_from_slice(&(own.len() as u64).to_le_bytes());
+    for item in own {
+        push_len_prefixed(&mut bytes, item.item_bytes());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let temp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+    fs::File::create(&temp_path)?.write_all(&bytes)?;
+    fs::rename(&temp_path, path)
+}
+
+fn push_len_prefixed(bytes: &mut Vec<u8>, item: &[u8]) {
+    bytes.extend_from_slice(&(item.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(item);
+}
+
+/// Loaded by [load].
+pub struct Snapshot<OwnType> {
+    pub own: Vec<OwnType>,
+    /// [Fingerprint::hash] of the data when it was generated. Compare it to [Fingerprint::hash]
+    /// of the loaded data (once "out" items are generated).
+    pub fingerprint_hash: u64,
+    pub out_items: usize,
+}
+
+/// Read a snapshot from `path`. Return `Ok(None)` if there is no such file. Return `Err` if the
+/// file is not a valid snapshot, or if it's for a different `key`.
+pub fn load<OwnType: OwnItem>(path: &Path, key: &str) -> Result<Option<Snapshot<OwnType>>, String> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(format!("Couldn't read {}: {err}", path.display())),
+    };
+    let mut reader = Reader { bytes: &bytes[..] };
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(format!("{} is not a data snapshot.", path.display()));
+    }
+    let format_version = u32::from_le_bytes(reader.take(4)?.try_into().unwrap());
+    if format_version != FORMAT_VERSION {
+        return Err(format!(
+            "{} has format version {format_version}, but expecting {FORMAT_VERSION}.",
+            path.display()
+        ));
+    }
+    if reader.take_len_prefixed()? != key.as_bytes() {
+        return Err(format!(
+            "{} is for a different configuration (a hash collision of keys).",
+            path.display()
+        ));
+    }
+    let fingerprint_hash = reader.take_u64()?;
+    let out_items = reader.take_u64()? as usize;
+    let num_items = reader.take_u64()? as usize;
+
+    let mut own = Vec::with_capacity(num_items.min(bytes.len() / 8));
+    for _ in 0..num_items {
+        own.push(OwnType::from_item_bytes(reader.take_len_prefixed()?)?);
+    }
+    if !reader.bytes.is_empty() {
+        return Err(format!("{} has extra bytes at the end.", path.display()));
+    }
+    Ok(Some(Snapshot {
+        own,
+        fingerprint_hash,
+        out_items,
+    }))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() < len {
+            return Err("Data snapshot is truncated.".to_owned());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn take_len_prefixed(&mut self) -> Result<&'a [u8], String> {
+        let len = self.take_u64()?;
+        self.take(usize::try_from(len).map_err(|_| "Data snapshot is corrupt.".to_owned())?)
+    }
+}
@@ -0,0 +1,795 @@
+//! Harness-agnostic description of benchmarks. Each harness backend ([crate::shared_iai],
+//! [crate::shared_criterion]) consumes a [BenchSpec], so that a workload defined once gets
+//! measured under any harness with identical data and identical benchmark IDs.
+use crate::chars::{CharClass, LenUnit};
+use crate::data::{self, MapWorkload, OwnAndOut, Payload, PushPop};
+use crate::dist::Distribution;
+use crate::fingerprint::ItemBytes;
+#[cfg(feature = "std")]
+use crate::outish::OutHashIndicator;
+use crate::outish::{
+    Out, OutCollRetriever, OutCollRetrieverCami, OutCollection, OutCollectionIndicator,
+    OutIndicatorIndicator, OutMapIndicator, OutRetriever,
+};
+use crate::rnd::{self, Random, RndBackend};
+use crate::snapshot::{self, OwnItem};
+use cami::Cami;
+
+/// Operation being benchmarked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operation {
+    SortStable,
+    SortUnstable,
+    BinSearch,
+    /// Priority-queue workload - see [BenchSpec::push_pop_workload].
+    PushPop,
+}
+
+impl Operation {
+    pub const ALL: [Operation; 4] = [
+        Operation::SortStable,
+        Operation::SortUnstable,
+        Operation::BinSearch,
+        Operation::PushPop,
+    ];
+
+    /// Benchmark ID. It's shared by all harnesses, and it's the same for [Ordering::Lexi] and
+    /// [Ordering::Cami], so that the harness can compare them side by side.
+    pub const fn id(self) -> &'static str {
+        match self {
+            Operation::SortStable => "stable",
+            Operation::SortUnstable => "unstable",
+            Operation::BinSearch => "bin_search",
+            Operation::PushPop => "push_pop",
+        }
+    }
+
+    /// Whether collection `OutCollectionType` supports this operation.
+    pub const fn is_supported<'out, T: Out + 'out, OutCollectionType: OutCollection<'out, T>>(
+        self,
+    ) -> bool {
+        match self {
+            Operation::SortStable => OutCollectionType::HAS_SORT,
+            Operation::SortUnstable => OutCollectionType::HAS_SORT_UNSTABLE,
+            Operation::BinSearch => {
+                OutCollectionType::HAS_BINARY_SEARCH
+                    && (OutCollectionType::HAS_SORT || OutCollectionType::HAS_SORT_UNSTABLE)
+            }
+            Operation::PushPop => OutCollectionType::HAS_POP,
+        }
+    }
+
+    /// Name of the benchmark function (as in `iai-callgrind`, or function ID in `Criterion`) for
+    /// this operation under the given `ordering`.
+    pub const fn function(self, ordering: Ordering) -> &'static str {
+        match (self, ordering) {
+            (Operation::SortStable | Operation::SortUnstable, Ordering::Lexi) => "sort_lexi",
+            (Operation::SortStable | Operation::SortUnstable, Ordering::Cami) => "sort_cami",
+            (Operation::BinSearch, Ordering::Lexi) => "bin_search_lexi",
+            (Operation::BinSearch, Ordering::Cami) => "bin_search_cami",
+            (Operation::PushPop, Ordering::Lexi) => "push_pop_lexi",
+            (Operation::PushPop, Ordering::Cami) => "push_pop_cami",
+        }
+    }
+}
+
+/// Which ordering the items are compared with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ordering {
+    /// "Classic" ordering (lexicographic).
+    Lexi,
+    /// [cami::Cami] ordering (potentially non-lexicographic).
+    Cami,
+}
+
+/// Operation on a map (see [MapSpec]) being benchmarked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MapOperation {
+    /// Insert all "out" items (as keys) with their payloads.
+    Build,
+    /// Look up each "out" item.
+    Lookup,
+    /// Range scans per [MapWorkload::range_queries].
+    Range,
+}
+
+impl MapOperation {
+    pub const ALL: [MapOperation; 3] = [
+        MapOperation::Build,
+        MapOperation::Lookup,
+        MapOperation::Range,
+    ];
+
+    /// Benchmark ID - like [Operation::id].
+    pub const fn id(self) -> &'static str {
+        match self {
+            MapOperation::Build => "map_build",
+            MapOperation::Lookup => "map_lookup",
+            MapOperation::Range => "map_range",
+        }
+    }
+
+    /// Name of the benchmark function - like [Operation::function].
+    pub const fn function(self, ordering: Ordering) -> &'static str {
+        match (self, ordering) {
+            (MapOperation::Build, Ordering::Lexi) => "map_build_lexi",
+            (MapOperation::Build, Ordering::Cami) => "map_build_cami",
+            (MapOperation::Lookup, Ordering::Lexi) => "map_lookup_lexi",
+            (MapOperation::Lookup, Ordering::Cami) => "map_lookup_cami",
+            (MapOperation::Range, Ordering::Lexi) => "map_range_lexi",
+            (MapOperation::Range, Ordering::Cami) => "map_range_cami",
+        }
+    }
+}
+
+/// Name of the benchmark function (like [Operation::function]) of looking up each "out" item in a
+/// hash-based collection (see [HashSpec]). Its benchmark ID is that of [Operation::BinSearch], so
+/// that harnesses compare it side by side with `bin_search_lexi` & `bin_search_cami`.
+#[cfg(feature = "std")]
+pub const HASH_LOOKUP_FUNCTION: &str = "hash_lookup";
+
+pub type SpecOut<Spec> =
+    OutRetriever<'static, <Spec as BenchSpec>::OutIndicatorIndicatorImpl, <Spec as BenchSpec>::Sub>;
+
+pub type SpecOutColl<Spec> = OutCollRetriever<
+    'static,
+    <Spec as BenchSpec>::OutCollectionIndicatorImpl,
+    <Spec as BenchSpec>::OutIndicatorIndicatorImpl,
+    <Spec as BenchSpec>::Sub,
+>;
+
+pub type SpecOutCollCami<Spec> = OutCollRetrieverCami<
+    'static,
+    <Spec as BenchSpec>::OutCollectionIndicatorImpl,
+    <Spec as BenchSpec>::OutIndicatorIndicatorImpl,
+    <Spec as BenchSpec>::Sub,
+>;
+
+pub type SpecOutMap<Spec> = <<Spec as MapSpec>::OutMapIndicatorImpl as OutMapIndicator>::OutMapImpl<
+    'static,
+    SpecOut<Spec>,
+    Payload,
+>;
+
+pub type SpecOutMapCami<Spec> =
+    <<Spec as MapSpec>::OutMapIndicatorImpl as OutMapIndicator>::OutMapImpl<
+        'static,
+        Cami<SpecOut<Spec>>,
+        Payload,
+    >;
+
+#[cfg(feature = "std")]
+pub type SpecOutHash<Spec> =
+    <<Spec as HashSpec>::OutHashIndicatorImpl as OutHashIndicator>::OutCollectionImpl<
+        'static,
+        SpecOut<Spec>,
+    >;
+
+/// One workload: item type, collection, and data source. The operations are derived from the
+/// collection (see [Operation::is_supported]).
+pub trait BenchSpec {
+    /// Base of the benchmark group name - see [BenchSpec::group_name].
+    const NAME: &'static str;
+
+    /// "Own" items, from which "out" items get generated (and which they may borrow from).
+    type Own: OwnItem + 'static;
+    /// `Sub` type, as per [crate::outish::OutIndicator].
+    type Sub: Out + 'static;
+    type OutIndicatorIndicatorImpl: OutIndicatorIndicator;
+    type OutCollectionIndicatorImpl: OutCollectionIndicator;
+    type Rnd: Random;
+
+    /// Max. number of items, for collections with a fixed capacity (like
+    /// [crate::outish::OutCollectionArray]). If there are more items (per
+    /// [BenchSpec::num_items_dist]), only the first ones are used - see [BenchSpec::capped].
+    const MAX_NUM_ITEMS: Option<usize> = None;
+
+    fn generate_own_item(rnd: &mut Self::Rnd) -> Self::Own;
+    fn generate_out_item(own: &'static Self::Own) -> SpecOut<Self>;
+
+    /// Distribution of the number of items. By default per environment variable `NUM_ITEMS_DIST`
+    /// (see [data::num_items_dist]).
+    fn num_items_dist() -> Distribution {
+        data::num_items_dist()
+    }
+    /// Distribution of item length, for items that have a length. By default per environment
+    /// variable `ITEM_LEN_DIST` (see [data::item_len_dist]). Implementations of
+    /// [BenchSpec::generate_own_item] should honor it.
+    fn item_len_dist() -> Distribution {
+        data::item_len_dist()
+    }
+
+    /// Class of characters, for string items. By default per environment variable `CHAR_CLASS`
+    /// (see [data::char_class]). Implementations of [BenchSpec::generate_own_item] should honor
+    /// it.
+    fn char_class() -> CharClass {
+        data::char_class()
+    }
+
+    /// Unit of length of string items. By default per environment variable `ITEM_LEN_UNIT` (see
+    /// [data::item_len_unit]). Implementations of [BenchSpec::generate_own_item] should honor it.
+    fn item_len_unit() -> LenUnit {
+        data::item_len_unit()
+    }
+
+    /// Any extra parameters of the data generation, to be a part of [BenchSpec::group_name].
+    fn params() -> Option<String> {
+        None
+    }
+
+    /// Benchmark group name: [BenchSpec::NAME], followed by any non-uniform distributions, any
+    /// non-default [BenchSpec::char_class], [RndBackend] (of [crate::rnd::BenchRnd]) &
+    /// [BenchSpec::item_len_unit] and any [BenchSpec::params]. For example:
+    /// `str items:uniform len:zipf:1.2 chars:cjk rnd:pcg32 len_unit:bytes`.
+    ///
+    /// `iai-callgrind` benchmark names are identifiers, so they can't contain this - there, use the
+    /// `group` name (in [crate::iai_bench_matrix]) or the bench name instead.
+    fn group_name() -> String {
+        let (num_items_dist, item_len_dist) = (Self::num_items_dist(), Self::item_len_dist());
+        let mut group_name = Self::NAME.to_owned();
+        if num_items_dist != Distribution::Uniform || item_len_dist != Distribution::Uniform {
+            group_name.push_str(&format!(" items:{num_items_dist} len:{item_len_dist}"));
+        }
+        let char_class = Self::char_class();
+        if char_class != CharClass::default() {
+            group_name.push_str(&format!(" chars:{char_class}"));
+        }
+        let rnd_backend = RndBackend::from_env();
+        if rnd_backend != RndBackend::default() {
+            group_name.push_str(&format!(" rnd:{rnd_backend}"));
+        }
+        let item_len_unit = Self::item_len_unit();
+        if item_len_unit != LenUnit::default() {
+            group_name.push_str(&format!(" len_unit:{item_len_unit}"));
+        }
+        if let Some(params) = Self::params() {
+            group_name.push(' ');
+            group_name.push_str(&params);
+        }
+        group_name
+    }
+
+    /// [BenchSpec::group_name], followed by [OwnAndOut::fingerprint] of `own_and_out`. For
+    /// example: `str fp:0123456789abcdef`.
+    fn group_name_for(own_and_out: &OwnAndOut<Self::Own, SpecOut<Self>>) -> String
+    where
+        SpecOut<Self>: ItemBytes,
+    {
+        format!("{} {}", Self::group_name(), own_and_out.fingerprint())
+    }
+
+    fn is_supported(operation: Operation) -> bool {
+        operation.is_supported::<SpecOut<Self>, SpecOutColl<Self>>()
+    }
+
+    fn operations() -> impl Iterator<Item = Operation> {
+        Operation::ALL
+            .into_iter()
+            .filter(|&operation| Self::is_supported(operation))
+    }
+
+    /// Generate "own" & "out" items, seeded by [Random::with_seed]. The number of items is per
+    /// [BenchSpec::num_items_dist]. If [snapshot::enabled], load "own" items from a snapshot
+    /// instead (or generate and save them) - see [BenchSpec::own_and_out_snapshot].
+    fn own_and_out() -> OwnAndOut<Self::Own, SpecOut<Self>>
+    where
+        SpecOut<Self>: ItemBytes,
+    {
+        let (env_var_name, seed) = rnd::seed_from_env();
+        let own_and_out = Self::own_and_out_snapshot(&format!("{env_var_name}={seed}"), || {
+            OwnAndOut::new_for_rnd_dist(
+                Self::generate_own_item,
+                Self::generate_out_item,
+                SpecOutColl::<Self>::ALLOWS_MULTIPLE_EQUAL_ITEMS,
+                Self::num_items_dist(),
+            )
+        });
+        Self::capped(own_and_out)
+    }
+
+    /// Like [BenchSpec::own_and_out], but the master seed is `seed` (for multi-seed runs - see
+    /// [rnd::seeds_from_env]).
+    fn own_and_out_for_seed(seed: &[u64]) -> OwnAndOut<Self::Own, SpecOut<Self>>
+    where
+        SpecOut<Self>: ItemBytes,
+    {
+        let seed_hex = rnd::format_seed_hex(seed);
+        let own_and_out =
+            Self::own_and_out_snapshot(&format!("{}={seed_hex}", rnd::RND_SEED_HEX_ENV), || {
+                let mut rnd = Self::Rnd::fork_words(seed, data::ITEMS_FORK_LABEL)
+                    .unwrap_or_else(|err| panic!("Seed {seed_hex}: {err}"));
+                OwnAndOut::new_for_data_dist(
+                    &mut rnd,
+                    Self::generate_own_item,
+                    Self::generate_out_item,
+                    SpecOutColl::<Self>::ALLOWS_MULTIPLE_EQUAL_ITEMS,
+                    Self::num_items_dist(),
+                )
+            });
+        Self::capped(own_and_out)
+    }
+
+    /// Keep only the first [BenchSpec::MAX_NUM_ITEMS] "own" items of `own_and_out` (if limited),
+    /// and their "out" items. (Snapshots keep all items.)
+    fn capped(
+        own_and_out: OwnAndOut<Self::Own, SpecOut<Self>>,
+    ) -> OwnAndOut<Self::Own, SpecOut<Self>> {
+        match Self::MAX_NUM_ITEMS {
+            Some(max_num_items) if own_and_out.own.len() > max_num_items => {
+                OwnAndOut::new_for_own_slice(
+                    &own_and_out.own[..max_num_items],
+                    Self::generate_out_item,
+                    SpecOutColl::<Self>::ALLOWS_MULTIPLE_EQUAL_ITEMS,
+                )
+            }
+            _ => own_and_out,
+        }
+    }
+
+    /// Priority-queue workload (for [Operation::PushPop]) over "out" items of `own_and_out` - see
+    /// [data::push_pop_workload]. It's seeded by [OwnAndOut::fingerprint], so it's the same for
+    /// the same data, regardless of harness (and of how the data was seeded).
+    fn push_pop_workload(own_and_out: &OwnAndOut<Self::Own, SpecOut<Self>>) -> Vec<PushPop>
+    where
+        SpecOut<Self>: ItemBytes,
+    {
+        let fingerprint = own_and_out.fingerprint();
+        let mut rnd = Self::Rnd::fork_words(&[fingerprint.hash], data::PUSH_POP_FORK_LABEL)
+            .unwrap_or_else(|err| panic!("Seed from {fingerprint}: {err}"));
+        data::push_pop_workload(&mut rnd, own_and_out.out.len())
+    }
+
+    /// Key of a snapshot of data for `seed` (the name & value of the seed's environment variable).
+    /// It consists of everything that the data depends on (apart from this crate's code - for that
+    /// see [crate::fingerprint::GENERATOR_VERSION]). That includes the resolved configuration
+    /// (even if default, so it's not omitted like in [BenchSpec::group_name]): [RndBackend],
+    /// [BenchSpec::num_items_dist], [BenchSpec::item_len_dist], [BenchSpec::char_class],
+    /// [BenchSpec::item_len_unit] and [data::prefix_tree].
+    fn snapshot_key(seed: &str) -> String {
+        format!(
+            "{} | {} | {seed} | items:{}..{} {} | len:{}..{} {} {} | chars:{} | rnd:{} | prefix_tree:{} | generator:{}",
+            core::any::type_name::<Self>(),
+            Self::group_name(),
+            data::min_items(),
+            data::max_items(),
+            Self::num_items_dist(),
+            data::min_item_len(),
+            data::max_item_len(),
+            Self::item_len_dist(),
+            Self::item_len_unit(),
+            Self::char_class(),
+            RndBackend::from_env(),
+            data::prefix_tree(),
+            crate::fingerprint::GENERATOR_VERSION
+        )
+    }
+
+    /// If [snapshot::enabled], load "own" items for `seed` (see [BenchSpec::snapshot_key]) from a
+    /// snapshot and generate "out" items from them. If there's no (valid) snapshot, `generate`,
+    /// and save a snapshot. Otherwise just `generate`.
+    fn own_and_out_snapshot(
+        seed: &str,
+        generate: impl FnOnce() -> OwnAndOut<Self::Own, SpecOut<Self>>,
+    ) -> OwnAndOut<Self::Own, SpecOut<Self>>
+    where
+        SpecOut<Self>: ItemBytes,
+    {
+        if !snapshot::enabled() {
+            return generate();
+        }
+        let key = Self::snapshot_key(seed);
+        let path = snapshot::path_for_key(Self::NAME, &key);
+        match snapshot::load::<Self::Own>(&path, &key) {
+            Ok(Some(loaded)) => {
+                let own_and_out = OwnAndOut::new_for_own(
+                    loaded.own,
+                    Self::generate_out_item,
+                    SpecOutColl::<Self>::ALLOWS_MULTIPLE_EQUAL_ITEMS,
+                );
+                if own_and_out.fingerprint().hash == loaded.fingerprint_hash {
+                    return own_and_out;
+                }
+                eprintln!(
+                    "Data snapshot {} doesn't match its fingerprint. Regenerating.",
+                    path.display()
+                );
+            }
+            Ok(None) => {}
+            Err(err) => eprintln!("{err} Regenerating."),
+        }
+        let own_and_out = generate();
+        if let Err(err) = snapshot::save(&path, &key, own_and_out.own, own_and_out.fingerprint()) {
+            eprintln!("Couldn't save data snapshot {}: {err}", path.display());
+        }
+        own_and_out
+    }
+}
+
+/// A [BenchSpec] whose "out" items are also keys of a map (see [crate::outish::OutMap]), with
+/// [Payload] values. Its [BenchSpec::OutCollectionIndicatorImpl] should not allow multiple equal
+/// items (as map keys are unique).
+pub trait MapSpec: BenchSpec {
+    type OutMapIndicatorImpl: OutMapIndicator;
+
+    /// Payloads & range queries over "out" items of `own_and_out` - see [data::map_workload].
+    /// Seeded by [OwnAndOut::fingerprint], like [BenchSpec::push_pop_workload].
+    fn map_workload(own_and_out: &OwnAndOut<Self::Own, SpecOut<Self>>) -> MapWorkload
+    where
+        SpecOut<Self>: ItemBytes,
+    {
+        let fingerprint = own_and_out.fingerprint();
+        let mut rnd = Self::Rnd::fork_words(&[fingerprint.hash], data::MAP_FORK_LABEL)
+            .unwrap_or_else(|err| panic!("Seed from {fingerprint}: {err}"));
+        data::map_workload(&mut rnd, own_and_out.out.len())
+    }
+}
+
+/// A [BenchSpec] with a hash-based baseline collection (see [OutHashIndicator]): its "out" items
+/// get looked up there, too (lexicographic vs. [Cami] ordering doesn't apply to hashing). See
+/// [HASH_LOOKUP_FUNCTION].
+#[cfg(feature = "std")]
+pub trait HashSpec: BenchSpec {
+    type OutHashIndicatorImpl: OutHashIndicator;
+}
+
+pub use specs::*;
+
+/// Workloads used by benches in `benches/`. Names match the bench names (see README.md).
+mod specs {
+    #[cfg(feature = "std")]
+    use super::HashSpec;
+    use super::{BenchSpec, MapSpec, SpecOut};
+    use crate::data::{self, Data};
+    use crate::outish::{
+        OutCollectionArrayIndicator, OutCollectionBTreeSetIndicator,
+        OutCollectionBinaryHeapIndicator, OutCollectionSliceIndicator,
+        OutCollectionVecDequeIndicator, OutCollectionVecDequeWrappedIndicator,
+        OutCollectionVecIndicator, OutIndicatorNonRefIndicator, OutIndicatorSliceIndicator,
+        OutIndicatorStrIndicator, OutMapBTreeMapIndicator,
+    };
+    #[cfg(feature = "std")]
+    use crate::outish::{OutCollectionHashMapIndicator, OutCollectionHashSetIndicator};
+    use crate::rnd::BenchRnd;
+
+    pub struct StackSliceU8;
+    impl BenchSpec for StackSliceU8 {
+        const NAME: &'static str = "u8";
+        type Own = u8;
+        type Sub = u8;
+        type OutIndicatorIndicatorImpl = OutIndicatorNonRefIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionSliceIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> u8 {
+            rnd.u8_any()
+        }
+        fn generate_out_item(own: &'static u8) -> SpecOut<Self> {
+            *own
+        }
+    }
+
+    pub struct AllocBTreeSetU8;
+    impl BenchSpec for AllocBTreeSetU8 {
+        const NAME: &'static str = "u8";
+        type Own = u8;
+        type Sub = u8;
+        type OutIndicatorIndicatorImpl = OutIndicatorNonRefIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionBTreeSetIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> u8 {
+            rnd.u8_any()
+        }
+        fn generate_out_item(own: &'static u8) -> SpecOut<Self> {
+            *own
+        }
+    }
+
+    pub struct AllocVecU8;
+    impl BenchSpec for AllocVecU8 {
+        const NAME: &'static str = "u8";
+        type Own = u8;
+        type Sub = u8;
+        type OutIndicatorIndicatorImpl = OutIndicatorNonRefIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> u8 {
+            rnd.u8_any()
+        }
+        fn generate_out_item(own: &'static u8) -> SpecOut<Self> {
+            *own
+        }
+    }
+
+    pub struct AllocVecU8Slice;
+    impl BenchSpec for AllocVecU8Slice {
+        const NAME: &'static str = "u8slice";
+        type Own = Vec<u8>;
+        type Sub = u8;
+        type OutIndicatorIndicatorImpl = OutIndicatorSliceIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> Vec<u8> {
+            rnd.bytes_for_len_dist(Self::item_len_dist())
+        }
+        fn generate_out_item(own: &'static Vec<u8>) -> SpecOut<Self> {
+            &own[..]
+        }
+    }
+
+    pub struct AllocVecStr;
+    impl BenchSpec for AllocVecStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        /// `&str` is special - see [OutIndicatorStrIndicator].
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            rnd.string_for_len_dist_class_unit(
+                Self::item_len_dist(),
+                Self::char_class(),
+                Self::item_len_unit(),
+            )
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+    }
+
+    /// Like [AllocVecStr], but in a [std::collections::VecDeque] (see
+    /// [crate::outish::OutCollectionVecDeque]).
+    pub struct AllocVecDequeStr;
+    impl BenchSpec for AllocVecDequeStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecDequeIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            AllocVecStr::generate_own_item(rnd)
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+    }
+
+    /// Like [AllocVecDequeStr], but wrapped around the end of the ring buffer (see
+    /// [crate::outish::OutCollectionVecDequeWrapped]).
+    pub struct AllocVecDequeWrappedStr;
+    impl BenchSpec for AllocVecDequeWrappedStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecDequeWrappedIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            AllocVecStr::generate_own_item(rnd)
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+        fn params() -> Option<String> {
+            Some("layout:wrapped".to_owned())
+        }
+    }
+
+    /// Like [AllocVecStr], but in a [std::collections::BinaryHeap] (see
+    /// [crate::outish::OutCollectionBinaryHeap]). Also for [super::Operation::PushPop].
+    pub struct AllocBinaryHeapStr;
+    impl BenchSpec for AllocBinaryHeapStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionBinaryHeapIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            AllocVecStr::generate_own_item(rnd)
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+    }
+
+    /// `&str` keys of a [std::collections::BTreeMap] (see [crate::outish::OutMapBTreeMap]), with
+    /// [crate::data::Payload] values. For [MapSpec] benches. (As a [BenchSpec] it's a
+    /// [std::collections::BTreeSet], so the keys are unique.)
+    pub struct AllocBTreeMapStr;
+    impl BenchSpec for AllocBTreeMapStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionBTreeSetIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            AllocVecStr::generate_own_item(rnd)
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+    }
+    impl MapSpec for AllocBTreeMapStr {
+        type OutMapIndicatorImpl = OutMapBTreeMapIndicator;
+    }
+
+    pub struct AllocVecString;
+    impl BenchSpec for AllocVecString {
+        const NAME: &'static str = "string";
+        type Own = String;
+        type Sub = String;
+        type OutIndicatorIndicatorImpl = OutIndicatorNonRefIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            rnd.string_for_len_dist_class_unit(
+                Self::item_len_dist(),
+                Self::char_class(),
+                Self::item_len_unit(),
+            )
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            own.clone()
+        }
+    }
+
+    /// Like [AllocVecStr], but with shared prefixes per [data::prefix_tree]. The suffix length is
+    /// per [BenchSpec::item_len_dist].
+    pub struct AllocVecStrPrefix;
+    impl BenchSpec for AllocVecStrPrefix {
+        const NAME: &'static str = "str_prefix";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            data::prefix_tree().string(rnd, Self::item_len_dist(), Self::char_class())
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+        fn params() -> Option<String> {
+            Some(format!("prefix:{}", data::prefix_tree()))
+        }
+    }
+
+    /// Like [AllocVecString], but with shared prefixes - see [AllocVecStrPrefix].
+    pub struct AllocVecStringPrefix;
+    impl BenchSpec for AllocVecStringPrefix {
+        const NAME: &'static str = "string_prefix";
+        type Own = String;
+        type Sub = String;
+        type OutIndicatorIndicatorImpl = OutIndicatorNonRefIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            data::prefix_tree().string(rnd, Self::item_len_dist(), Self::char_class())
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            own.clone()
+        }
+        fn params() -> Option<String> {
+            Some(format!("prefix:{}", data::prefix_tree()))
+        }
+    }
+
+    /// Like [AllocVecU8Slice], but with shared prefixes - see [AllocVecStrPrefix].
+    pub struct AllocVecU8SlicePrefix;
+    impl BenchSpec for AllocVecU8SlicePrefix {
+        const NAME: &'static str = "u8slice_prefix";
+        type Own = Vec<u8>;
+        type Sub = u8;
+        type OutIndicatorIndicatorImpl = OutIndicatorSliceIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> Vec<u8> {
+            data::prefix_tree().bytes(rnd, Self::item_len_dist(), Self::char_class())
+        }
+        fn generate_out_item(own: &'static Vec<u8>) -> SpecOut<Self> {
+            &own[..]
+        }
+        fn params() -> Option<String> {
+            Some(format!("prefix:{}", data::prefix_tree()))
+        }
+    }
+
+    /// Like [AllocVecStr], plus a [std::collections::HashSet] baseline (see
+    /// [crate::outish::OutCollectionHashSet]).
+    #[cfg(feature = "std")]
+    pub struct StdlbHashSetStr;
+    #[cfg(feature = "std")]
+    impl BenchSpec for StdlbHashSetStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            AllocVecStr::generate_own_item(rnd)
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+        fn params() -> Option<String> {
+            Some("hash:set".to_owned())
+        }
+    }
+    #[cfg(feature = "std")]
+    impl HashSpec for StdlbHashSetStr {
+        type OutHashIndicatorImpl = OutCollectionHashSetIndicator;
+    }
+
+    /// Like [StdlbHashSetStr], but the baseline is a [std::collections::HashMap] of item counts
+    /// (see [crate::outish::OutCollectionHashMap]).
+    #[cfg(feature = "std")]
+    pub struct StdlbHashMapStr;
+    #[cfg(feature = "std")]
+    impl BenchSpec for StdlbHashMapStr {
+        const NAME: &'static str = "str";
+        type Own = String;
+        type Sub = &'static str;
+        type OutIndicatorIndicatorImpl = OutIndicatorStrIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+        type Rnd = BenchRnd;
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> String {
+            AllocVecStr::generate_own_item(rnd)
+        }
+        fn generate_out_item(own: &'static String) -> SpecOut<Self> {
+            &own[..]
+        }
+        fn params() -> Option<String> {
+            Some("hash:map".to_owned())
+        }
+    }
+    #[cfg(feature = "std")]
+    impl HashSpec for StdlbHashMapStr {
+        type OutHashIndicatorImpl = OutCollectionHashMapIndicator;
+    }
+
+    /// Capacity of [StackArrayU8].
+    pub const STACK_ARRAY_CAPACITY: usize = 4096;
+
+    /// Like [StackSliceU8], but in a fixed-capacity array (see
+    /// [crate::outish::OutCollectionArray]), so the benchmarked operations don't touch the heap at
+    /// all. At most [STACK_ARRAY_CAPACITY] items.
+    pub struct StackArrayU8;
+    impl BenchSpec for StackArrayU8 {
+        const NAME: &'static str = "u8";
+        type Own = u8;
+        type Sub = u8;
+        type OutIndicatorIndicatorImpl = OutIndicatorNonRefIndicator;
+        type OutCollectionIndicatorImpl = OutCollectionArrayIndicator<STACK_ARRAY_CAPACITY>;
+        type Rnd = BenchRnd;
+
+        const MAX_NUM_ITEMS: Option<usize> = Some(STACK_ARRAY_CAPACITY);
+
+        fn generate_own_item(rnd: &mut BenchRnd) -> u8 {
+            rnd.u8_any()
+        }
+        fn generate_out_item(own: &'static u8) -> SpecOut<Self> {
+            *own
+        }
+        fn params() -> Option<String> {
+            Some(format!("capacity:{STACK_ARRAY_CAPACITY}"))
+        }
+    }
+}
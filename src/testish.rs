@@ -1,4 +1,5 @@
 use crate::data::{Data, DataItems, OptAsData};
+use crate::dist::Distribution;
 use alloc::vec::IntoIter;
 
 extern crate alloc;
@@ -10,7 +11,8 @@ impl<T> DataItems for DataTest<T>
 where
     DataTest<T>: OptAsData,
 {
-    fn num_items(&mut self) -> usize {
+    /// Ignore `dist`: the number of items is given.
+    fn num_items_for_dist(&mut self, _dist: Distribution) -> usize {
         self.0.len()
     }
 }
@@ -0,0 +1,39 @@
+use cami_benches::col;
+use cami_benches::outish::{OutCollection, OutCollectionArray};
+use cami_benches::spec::{BenchSpec, Operation, StackArrayU8, STACK_ARRAY_CAPACITY};
+
+#[test]
+fn sort_and_search() {
+    let sorted = col::lexi_indicated::<u8, OutCollectionArray<u8, 8>>(&[5, 3, 9, 3, 1], false);
+    assert!(sorted.is_sorted());
+    assert_eq!(sorted.iter().copied().collect::<Vec<_>>(), [1, 3, 3, 5, 9]);
+    assert!(sorted.binary_search(&9));
+    assert!(!sorted.binary_search(&4));
+}
+
+#[test]
+fn clone_clear_and_drop_owned_items() {
+    let mut col = OutCollectionArray::<String, 4>::new();
+    col.extend(["b".to_owned(), "a".to_owned()]);
+    let mut clone = col.clone();
+    clone.sort_unstable();
+    assert_eq!(clone.iter().collect::<Vec<_>>(), ["a", "b"]);
+    col.clear();
+    assert_eq!(col.len(), 0);
+    col.extend(["c".to_owned()]);
+    assert_eq!(col.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Fixed capacity 2 exceeded.")]
+fn over_capacity() {
+    let mut col = OutCollectionArray::<u8, 2>::new();
+    col.extend([1, 2, 3]);
+}
+
+#[test]
+fn spec_is_capped() {
+    assert!(!StackArrayU8::is_supported(Operation::SortStable));
+    assert!(StackArrayU8::is_supported(Operation::SortUnstable));
+    assert!(StackArrayU8::own_and_out_for_seed(&[1]).out.len() <= STACK_ARRAY_CAPACITY);
+}
@@ -0,0 +1,49 @@
+use cami_benches::col;
+use cami_benches::data::{self, PushPop};
+use cami_benches::outish::{OutCollection, OutCollectionBinaryHeap};
+use cami_benches::prng::Xoshiro256StarStar;
+use cami_benches::rnd::Random;
+use cami_benches::spec::{AllocBinaryHeapStr, BenchSpec, Operation};
+
+const ITEMS: [&str; 6] = ["d", "bb", "a", "ccc", "a", "e"];
+
+#[test]
+fn sort_and_search() {
+    let sorted = col::lexi_indicated::<&str, OutCollectionBinaryHeap<&str>>(&ITEMS, false);
+    assert!(sorted.is_sorted());
+    assert_eq!(sorted.sorted, ["a", "a", "bb", "ccc", "d", "e"]);
+    assert!(sorted.binary_search(&"ccc"));
+    assert!(!sorted.binary_search(&"c"));
+}
+
+#[test]
+fn operations() {
+    let operations = AllocBinaryHeapStr::operations().collect::<Vec<_>>();
+    assert_eq!(operations, [Operation::SortUnstable, Operation::PushPop]);
+}
+
+#[test]
+fn pop_greatest_after_sort() {
+    let mut col = col::lexi_indicated::<&str, OutCollectionBinaryHeap<&str>>(&ITEMS, false);
+    col.extend(["b"]);
+    assert_eq!(col.pop(), Some("e"));
+    assert_eq!(col.len(), ITEMS.len());
+}
+
+#[test]
+fn push_pop_workload() {
+    let mut rnd = Xoshiro256StarStar::fork_words(&[1], data::PUSH_POP_FORK_LABEL).unwrap();
+    let workload = data::push_pop_workload(&mut rnd, 100);
+    let pushes = workload
+        .iter()
+        .filter(|step| matches!(step, PushPop::Push(_)))
+        .count();
+    assert_eq!(pushes, 100);
+    assert_eq!(workload.len(), 200);
+    assert_eq!(workload[0], PushPop::Push(0));
+
+    let out = (0..100).map(|i| i.to_string()).collect::<Vec<_>>();
+    let out = out.iter().map(|item| &item[..]).collect::<Vec<_>>();
+    let col = col::push_pop_lexi_indicated::<&str, OutCollectionBinaryHeap<&str>>(&out, &workload);
+    assert_eq!(col.len(), 0);
+}
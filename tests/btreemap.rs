@@ -0,0 +1,83 @@
+use cami::prelude::*;
+use cami_benches::col;
+use cami_benches::data::{self, Payload, RangeQuery};
+use cami_benches::outish::{OutMap, OutMapBTreeMap};
+use cami_benches::prng::Xoshiro256StarStar;
+use cami_benches::rnd::Random;
+
+const ITEMS: [&str; 5] = ["d", "bb", "a", "ccc", "e"];
+
+fn payloads() -> Vec<Payload> {
+    (0..ITEMS.len()).map(|i| Payload([i, 0])).collect()
+}
+
+#[test]
+fn build_and_get() {
+    let map =
+        col::map_build_lexi_indicated::<&str, OutMapBTreeMap<&str, Payload>>(&ITEMS, &payloads());
+    assert_eq!(map.len(), ITEMS.len());
+    assert_eq!(map.get(&"ccc"), Some(&Payload([3, 0])));
+    assert_eq!(map.get(&"c"), None);
+    col::map_lookup(&map, &ITEMS);
+}
+
+#[test]
+fn range_from() {
+    let map =
+        col::map_build_lexi_indicated::<&str, OutMapBTreeMap<&str, Payload>>(&ITEMS, &payloads());
+    let keys = map
+        .range_from(&"b")
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, ["bb", "ccc", "d", "e"]);
+
+    let cami = col::map_build_cami_indicated::<&str, OutMapBTreeMap<Cami<&str>, Payload>>(
+        &ITEMS,
+        &payloads(),
+    );
+    // Cami ordering: by length first. Payloads are indexes to ITEMS.
+    let indexes = cami
+        .range_from(&Cami::new("d"))
+        .map(|(_, payload)| payload.0[0])
+        .collect::<Vec<_>>();
+    assert_eq!(indexes, [0, 4, 1, 3]);
+}
+
+#[test]
+fn map_workload() {
+    let mut rnd = Xoshiro256StarStar::fork_words(&[1], data::MAP_FORK_LABEL).unwrap();
+    let workload = data::map_workload(&mut rnd, 100);
+    assert_eq!(workload.payloads.len(), 100);
+    assert_eq!(workload.range_queries.len(), 12);
+    assert!(workload
+        .range_queries
+        .iter()
+        .all(|query| query.from < 100 && (1..=data::MAX_RANGE_QUERY_LEN).contains(&query.len)));
+
+    let empty = data::map_workload(&mut rnd, 0);
+    assert!(empty.payloads.is_empty() && empty.range_queries.is_empty());
+}
+
+#[test]
+fn map_range_visits_len_entries() {
+    let map =
+        col::map_build_lexi_indicated::<&str, OutMapBTreeMap<&str, Payload>>(&ITEMS, &payloads());
+    // From "bb", up to 2 entries: "bb" (1) and "ccc" (3).
+    let sum = col::map_range(&map, &ITEMS, &[RangeQuery { from: 1, len: 2 }]);
+    assert_eq!(sum, 4);
+    // From "e", wrapping around: "e" (4), "a" (2) and "bb" (1).
+    let sum = col::map_range(&map, &ITEMS, &[RangeQuery { from: 4, len: 3 }]);
+    assert_eq!(sum, 7);
+    // Each entry at most once.
+    let sum = col::map_range(&map, &ITEMS, &[RangeQuery { from: 4, len: 7 }]);
+    assert_eq!(sum, 10);
+
+    let map_cami = col::map_build_cami_indicated::<&str, OutMapBTreeMap<Cami<&str>, Payload>>(
+        &ITEMS,
+        &payloads(),
+    );
+    let keys_cami = ITEMS.map(Cami::new);
+    // From "e" in Cami order ("a", "d", "e", "bb", "ccc"): "e" (4), "bb" (1) and "ccc" (3).
+    let sum = col::map_range(&map_cami, &keys_cami, &[RangeQuery { from: 4, len: 3 }]);
+    assert_eq!(sum, 8);
+}
@@ -0,0 +1,54 @@
+use cami_benches::chars::CharClass;
+use cami_benches::data::Data;
+use cami_benches::dist::Distribution;
+use cami_benches::prng::Xoshiro256StarStar;
+use core::str::FromStr;
+
+#[test]
+fn display_from_str_round_trip() {
+    let mix = CharClass::Mix {
+        weights: [4, 0, 1, 1, 2],
+    };
+    for class in CharClass::PURE.into_iter().chain([mix]) {
+        assert_eq!(CharClass::from_str(&class.to_string()), Ok(class));
+    }
+    assert!(CharClass::from_str("mix:1:2").is_err());
+    assert!(CharClass::from_str("mix:0:0:0:0:0").is_err());
+    assert!(CharClass::from_str("cjk:1").is_err());
+    assert!(CharClass::from_str("greek").is_err());
+}
+
+#[test]
+fn utf8_len_per_class() {
+    let mut rng = Xoshiro256StarStar::new(1);
+    for (class, utf8_len) in CharClass::PURE.into_iter().zip([1, 1, 2, 3, 4]) {
+        for _ in 0..1000 {
+            assert_eq!(rng.char_for_class(class).len_utf8(), utf8_len, "{class}");
+        }
+    }
+}
+
+#[test]
+fn mix_honors_weights() {
+    let mut rng = Xoshiro256StarStar::new(1);
+    let class = CharClass::Mix {
+        weights: [0, 0, 0, 1, 1],
+    };
+    let string = rng.string_for_len_range_dist_class(1000..1001, Distribution::Uniform, class);
+    assert_eq!(string.chars().count(), 1000);
+    let cjk = string.chars().filter(|c| c.len_utf8() == 3).count();
+    let emoji = string.chars().filter(|c| c.len_utf8() == 4).count();
+    assert_eq!(cjk + emoji, 1000);
+    assert!(cjk > 400 && emoji > 400);
+}
+
+#[test]
+fn string_for_byte_len_is_exact() {
+    let mut rng = Xoshiro256StarStar::new(1);
+    for class in CharClass::PURE {
+        for byte_len in 0..20 {
+            assert_eq!(rng.string_for_byte_len(byte_len, class).len(), byte_len);
+        }
+    }
+    assert_eq!(rng.bytes_for_len(7).len(), 7);
+}
@@ -0,0 +1,30 @@
+use cami_benches::dataset_stats::DatasetStats;
+
+#[test]
+fn stats_of_strs() {
+    let stats = DatasetStats::new(&["abc", "abd", "b", "", "xy"]);
+    assert_eq!(stats.items, 5);
+    assert_eq!(stats.min_len, 0);
+    assert_eq!(stats.max_len, 3);
+    assert_eq!(stats.mean_len, 1.8);
+    assert_eq!(stats.distinct_lens, 4);
+    // Sorted: "", "abc", "abd", "b", "xy" => common prefixes 0, 2, 0, 0.
+    assert_eq!(stats.mean_lcp, 0.5);
+    // 10 pairs, only "abc" & "abd" have the same length.
+    assert_eq!(stats.len_decidable, 0.9);
+    assert_eq!(
+        stats.to_string(),
+        "len:0..=3 mean_len:1.800 lens:4 lcp:0.500 len_decidable:0.900"
+    );
+}
+
+#[test]
+fn stats_of_u8s_and_empty() {
+    let stats = DatasetStats::new(&[3u8, 1, 2]);
+    assert_eq!(stats.distinct_lens, 1);
+    assert_eq!(stats.len_decidable, 0.0);
+
+    let stats = DatasetStats::new::<&str>(&[]);
+    assert_eq!((stats.min_len, stats.max_len, stats.mean_len), (0, 0, 0.0));
+    assert_eq!((stats.mean_lcp, stats.len_decidable), (0.0, 0.0));
+}
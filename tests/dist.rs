@@ -0,0 +1,47 @@
+use cami_benches::dist::Distribution;
+use cami_benches::prng::Xoshiro256StarStar;
+use core::str::FromStr;
+
+const DISTRIBUTIONS: [Distribution; 6] = [
+    Distribution::Uniform,
+    Distribution::Constant,
+    Distribution::Zipf { exponent: 1.2 },
+    Distribution::LogNormal {
+        mu: 3.0,
+        sigma: 1.5,
+    },
+    Distribution::Exponential { mean: 50.0 },
+    Distribution::Bimodal { low_weight: 0.9 },
+];
+
+#[test]
+fn display_from_str_round_trip() {
+    for dist in DISTRIBUTIONS {
+        assert_eq!(Distribution::from_str(&dist.to_string()), Ok(dist));
+    }
+    assert!(Distribution::from_str("zipf").is_err());
+    assert!(Distribution::from_str("lognormal:1").is_err());
+    assert!(Distribution::from_str("pareto:1").is_err());
+}
+
+#[test]
+fn sample_within_range() {
+    let mut rng = Xoshiro256StarStar::new(1);
+    for dist in DISTRIBUTIONS {
+        for range in [5..6, 1..1_000, 1_000..500_000] {
+            for _ in 0..1_000 {
+                assert!(range.contains(&dist.sample(&mut rng, range.clone())));
+            }
+        }
+    }
+}
+
+#[test]
+fn zipf_favours_short() {
+    let mut rng = Xoshiro256StarStar::new(2);
+    let dist = Distribution::Zipf { exponent: 1.2 };
+    let num_short = (0..1_000)
+        .filter(|_| dist.sample(&mut rng, 1..1_000) < 100)
+        .count();
+    assert!(num_short > 700, "{num_short}");
+}
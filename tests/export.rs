@@ -0,0 +1,53 @@
+use cami_benches::data::Data;
+use cami_benches::data::OwnAndOut;
+use cami_benches::export::{self, DatasetSummary, ExportFormat};
+use cami_benches::file_data::DataFile;
+
+fn own_and_out(items: &[&str]) -> OwnAndOut<String, &'static str> {
+    let mut data = DataFile::new(items.iter().map(|item| item.as_bytes().to_vec()).collect());
+    OwnAndOut::new_for_data(&mut data, |d| d.string(), |own| &own[..], false)
+}
+
+#[test]
+fn summary() {
+    let summary = DatasetSummary::new(&["abc", "abd", "b", "abc", ""]);
+    assert_eq!(summary.items, 5);
+    assert_eq!(summary.distinct_items, 4);
+    assert_eq!(
+        summary.len_histogram.into_iter().collect::<Vec<_>>(),
+        [(0, 1), (1, 1), (3, 3)]
+    );
+    // Sorted: "", "abc", "abc", "abd", "b" => common prefixes 0, 3, 2, 0.
+    assert_eq!(summary.avg_common_prefix_len, 1.25);
+
+    assert_eq!(DatasetSummary::new::<u8>(&[]).avg_common_prefix_len, 0.0);
+}
+
+#[test]
+fn ndjson() {
+    let mut written = Vec::new();
+    export::write_items(
+        &mut written,
+        ExportFormat::Ndjson,
+        &own_and_out(&["b\"\n", "a"]),
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(written).unwrap(),
+        r#"{"set":"own","index":0,"len":3,"item":"b\"\n"}
+{"set":"own","index":1,"len":1,"item":"a"}
+{"set":"out","index":0,"len":1,"item":"a"}
+{"set":"out","index":1,"len":3,"item":"b\"\n"}
+"#
+    );
+}
+
+#[test]
+fn csv() {
+    let mut written = Vec::new();
+    export::write_items(&mut written, ExportFormat::Csv, &own_and_out(&["x,\"y\""])).unwrap();
+    assert_eq!(
+        String::from_utf8(written).unwrap(),
+        "set,index,len,item\nown,0,5,\"x,\"\"y\"\"\"\nout,0,5,\"x,\"\"y\"\"\"\n"
+    );
+}
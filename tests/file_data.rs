@@ -0,0 +1,69 @@
+use cami_benches::data::{Data, OwnAndOut};
+use cami_benches::file_data::{DataFile, FileFormat};
+use std::path::Path;
+
+#[test]
+fn lines() {
+    let items = FileFormat::Lines.parse(b"b\r\na\n\nc\n").unwrap();
+    assert_eq!(items, [&b"b"[..], b"a", b"", b"c"]);
+    assert!(FileFormat::Lines.parse(b"").unwrap().is_empty());
+}
+
+#[test]
+fn ndjson_strings() {
+    let items = FileFormat::NdjsonStrings
+        .parse(
+            r#"["a", "b\"c"]
+
+ "é😀\n"
+[]"#
+            .as_bytes(),
+        )
+        .unwrap();
+    assert_eq!(
+        items,
+        [b"a".to_vec(), b"b\"c".to_vec(), "é😀\n".as_bytes().to_vec()]
+    );
+    assert!(FileFormat::NdjsonStrings.parse(b"[\"a\" \"b\"]").is_err());
+    assert!(FileFormat::NdjsonStrings.parse(b"[1]").is_err());
+    assert!(FileFormat::NdjsonStrings.parse(b"\"a").is_err());
+}
+
+#[test]
+fn length_prefixed() {
+    let mut bytes = Vec::new();
+    for item in [&b"xy"[..], b"", b"\0\xff"] {
+        bytes.extend_from_slice(&(item.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(item);
+    }
+    let items = FileFormat::LengthPrefixed.parse(&bytes).unwrap();
+    assert_eq!(items, [&b"xy"[..], b"", b"\0\xff"]);
+    assert!(FileFormat::LengthPrefixed
+        .parse(&bytes[..bytes.len() - 1])
+        .is_err());
+}
+
+#[test]
+fn format_from_path() {
+    assert_eq!(
+        FileFormat::from_path(Path::new("keys.jsonl")),
+        FileFormat::NdjsonStrings
+    );
+    assert_eq!(
+        FileFormat::from_path(Path::new("keys.bin")),
+        FileFormat::LengthPrefixed
+    );
+    assert_eq!(
+        FileFormat::from_path(Path::new("keys.txt")),
+        FileFormat::Lines
+    );
+}
+
+#[test]
+fn own_and_out_for_file_items() {
+    let mut data = DataFile::new(vec![b"b".to_vec(), b"a".to_vec(), b"b".to_vec()]);
+    let own_and_out =
+        OwnAndOut::new_for_data(&mut data, |d| d.string(), |string| &string[..], false);
+    assert_eq!(own_and_out.own, ["b", "a", "b"]);
+    assert_eq!(own_and_out.out, ["a", "b"]);
+}
@@ -0,0 +1,47 @@
+use cami_benches::chars::CharClass;
+use cami_benches::data::{Data, OwnAndOut, ITEMS_FORK_LABEL};
+use cami_benches::dist::Distribution;
+use cami_benches::fingerprint::Fingerprint;
+use cami_benches::prng::Xoshiro256StarStar;
+use cami_benches::rnd::Random;
+
+/// Catch accidental changes of data generation. If a change is intentional, bump
+/// [cami_benches::fingerprint::GENERATOR_VERSION] and update the expected hash.
+#[test]
+fn pinned_for_known_seed() {
+    let mut rnd = Xoshiro256StarStar::fork_words(&[1], ITEMS_FORK_LABEL).unwrap();
+    // The number of items is fixed here, rather than per (environment-dependent) MIN_ITEMS.
+    let own = (0..1000)
+        .map(|_| {
+            rnd.string_for_len_range_dist_class(
+                1..20,
+                Distribution::Uniform,
+                CharClass::Mix {
+                    weights: [4, 1, 1, 1, 1],
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let own_and_out = OwnAndOut::new_for_own(own, |own| &own[..], false);
+    let fingerprint = own_and_out.fingerprint();
+    assert_eq!(fingerprint.own_items, 1000);
+    assert_eq!(fingerprint.out_items, 997);
+    assert_eq!(fingerprint.to_string(), "fp:91ca6a1013181171");
+}
+
+#[test]
+fn item_boundaries_matter() {
+    let joined = Fingerprint::new(&["ab", ""], &["ab", ""]);
+    let split = Fingerprint::new(&["a", "b"], &["a", "b"]);
+    assert_ne!(joined.hash, split.hash);
+    assert_eq!(joined.own_bytes, split.own_bytes);
+}
+
+#[test]
+fn out_items_matter() {
+    let own = ["b", "a"];
+    let unsorted = Fingerprint::new(&own, &own);
+    let sorted = Fingerprint::new(&own, &["a", "b"]);
+    assert_ne!(unsorted.hash, sorted.hash);
+    assert_eq!(unsorted.out_items, sorted.out_items);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+use cami_benches::col;
+use cami_benches::outish::{OutCollection, OutCollectionHashMap, OutCollectionHashSet};
+use cami_benches::spec::{
+    BenchSpec, Operation, StdlbHashMapStr, StdlbHashSetStr, HASH_LOOKUP_FUNCTION,
+};
+
+const ITEMS: [&str; 6] = ["d", "bb", "a", "ccc", "a", "e"];
+
+#[test]
+fn hash_set_lookup() {
+    let hashed = col::hash_indicated::<&str, OutCollectionHashSet<&str>>(&ITEMS);
+    assert_eq!(hashed.len(), 5);
+    assert!(ITEMS.iter().all(|item| hashed.binary_search(item)));
+    assert!(!hashed.binary_search(&"c"));
+}
+
+#[test]
+fn hash_map_counts_equal_items() {
+    let mut hashed = col::hash_indicated::<&str, OutCollectionHashMap<&str>>(&ITEMS);
+    assert_eq!(hashed.len(), ITEMS.len());
+    assert_eq!(hashed.counts[&"a"], 2);
+    let mut items = hashed.iter().copied().collect::<Vec<_>>();
+    items.sort();
+    assert_eq!(items, ["a", "a", "bb", "ccc", "d", "e"]);
+    assert!(hashed.binary_search(&"ccc"));
+
+    hashed.clear();
+    assert_eq!(hashed.len(), 0);
+    assert!(!hashed.binary_search(&"ccc"));
+}
+
+#[test]
+fn hash_specs() {
+    assert_eq!(HASH_LOOKUP_FUNCTION, "hash_lookup");
+    assert!(StdlbHashSetStr::is_supported(Operation::BinSearch));
+    assert_ne!(StdlbHashSetStr::group_name(), StdlbHashMapStr::group_name());
+}
@@ -0,0 +1,43 @@
+use cami_benches::chars::CharClass;
+use cami_benches::dist::Distribution;
+use cami_benches::prefix::PrefixTree;
+use cami_benches::prng::Xoshiro256StarStar;
+use core::str::FromStr;
+
+#[test]
+fn display_from_str_round_trip() {
+    let tree = PrefixTree {
+        depth: 3,
+        fan_out: 2,
+        prefix_len: 5,
+    };
+    assert_eq!(PrefixTree::from_str(&tree.to_string()), Ok(tree));
+    assert!(PrefixTree::from_str("3:0:5").is_err());
+    assert!(PrefixTree::from_str("3:2").is_err());
+}
+
+#[test]
+fn items_share_prefixes() {
+    let tree = PrefixTree {
+        depth: 2,
+        fan_out: 2,
+        prefix_len: 4,
+    };
+    let mut rng = Xoshiro256StarStar::new(1);
+    let items: Vec<String> = (0..100)
+        .map(|_| tree.string(&mut rng, Distribution::Uniform, CharClass::Cjk))
+        .collect();
+
+    let mut first_segments: Vec<&str> = items.iter().map(|item| &item[..4]).collect();
+    first_segments.sort();
+    first_segments.dedup();
+    assert_eq!(first_segments.len(), 2);
+
+    let mut prefixes: Vec<&str> = items
+        .iter()
+        .map(|item| &item[..tree.common_prefix_len()])
+        .collect();
+    prefixes.sort();
+    prefixes.dedup();
+    assert_eq!(prefixes.len(), 4);
+}
@@ -0,0 +1,40 @@
+use cami_benches::data::Data;
+use cami_benches::prng::{Pcg32, SplitMix64, Xoshiro256StarStar};
+use cami_benches::rnd::Random;
+
+/// Reference output of SplitMix64 for seed 0.
+#[test]
+fn split_mix_64_reference() {
+    let mut split_mix = SplitMix64::new(0);
+    assert_eq!(split_mix.next_u64(), 0xE220_A839_7B1D_CDAF);
+    assert_eq!(split_mix.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+}
+
+/// Reference output of `pcg32-demo` (seed 42, stream 54).
+#[test]
+fn pcg32_reference() {
+    let mut pcg = Pcg32::new_with_stream(42, 54);
+    assert_eq!(
+        [pcg.next_u32(), pcg.next_u32(), pcg.next_u32()],
+        [0xA15C_02B7, 0x7B47_F409, 0xBA1D_3330]
+    );
+}
+
+#[test]
+fn within_range() {
+    let mut xoshiro = Xoshiro256StarStar::new(1);
+    for _ in 0..1000 {
+        assert!((3..10).contains(&xoshiro.usize(3..10)));
+        assert!((0.0..1.0).contains(&xoshiro.f64()));
+    }
+}
+
+#[test]
+fn seed_word_count_checked() {
+    assert!(SplitMix64::with_seed_words(&[]).is_err());
+    assert!(SplitMix64::with_seed_words(&[1, 2]).is_err());
+    assert!(Pcg32::with_seed_words(&[]).is_err());
+    assert!(Pcg32::with_seed_words(&[1, 2]).is_ok());
+    assert!(Pcg32::with_seed_words(&[1, 2, 3]).is_err());
+    assert!(Xoshiro256StarStar::with_seed_words(&[]).is_err());
+}
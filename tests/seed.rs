@@ -0,0 +1,45 @@
+use cami_benches::data::Data;
+use cami_benches::prng::{Pcg32, Xoshiro256StarStar};
+use cami_benches::rnd::{self, Random};
+
+#[test]
+fn parse_seed_words() {
+    assert_eq!(rnd::parse_seed("42", 10, 1), Ok(vec![42]));
+    assert_eq!(rnd::parse_seed(" 1_000\t0x2A ", 10, 2), Ok(vec![1000, 42]));
+    assert_eq!(
+        rnd::parse_seed("ff 0xFF dead_beef", 16, 4),
+        Ok(vec![255, 255, 0xDEAD_BEEF])
+    );
+    assert!(rnd::parse_seed("", 10, 1).is_err());
+    assert!(rnd::parse_seed("1 2", 10, 1).is_err());
+    assert!(rnd::parse_seed("ff", 10, 1).is_err());
+    assert!(rnd::parse_seed("18446744073709551616", 10, 1).is_err());
+}
+
+#[test]
+fn words_per_backend() {
+    let mut with_stream = Pcg32::with_seed_words(&[42, 54]).unwrap();
+    assert_eq!(with_stream.next_u32(), 0xA15C_02B7);
+
+    assert!(Xoshiro256StarStar::with_seed_words(&[1, 2]).is_err());
+    assert!(Xoshiro256StarStar::with_seed_words(&[0, 0, 0, 0]).is_err());
+    let mut from_state = Xoshiro256StarStar::with_seed_words(&[1, 2, 3, 4]).unwrap();
+    assert!((0..10).contains(&from_state.usize(0..10)));
+}
+
+#[test]
+#[should_panic(expected = "RND_SEED_HEX")]
+fn malformed_seed_names_env_var() {
+    Pcg32::with_seed_hex("xyz");
+}
+
+#[test]
+fn fork_seed_words_per_label() {
+    let master = [1, 2];
+    let items = rnd::fork_seed_words(&master, "items");
+    assert_eq!(items.len(), master.len());
+    assert_eq!(items, rnd::fork_seed_words(&master, "items"));
+    assert_ne!(items, rnd::fork_seed_words(&master, "queries"));
+    assert_ne!(items, rnd::fork_seed_words(&[1, 3], "items"));
+    assert_ne!(items[0], items[1]);
+}
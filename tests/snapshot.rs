@@ -0,0 +1,97 @@
+use cami_benches::data::Data;
+use cami_benches::dist::Distribution;
+use cami_benches::fingerprint::Fingerprint;
+use cami_benches::outish::{OutCollectionVecIndicator, OutIndicatorSliceIndicator};
+use cami_benches::rnd::BenchRnd;
+use cami_benches::snapshot;
+use cami_benches::spec::{BenchSpec, SpecOut};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "cami-benches-{}-{name}.snapshot",
+        std::process::id()
+    ))
+}
+
+#[test]
+fn save_load_round_trip() {
+    let path = temp_path("round-trip");
+    let own = vec!["".to_owned(), "abc".to_owned(), "žluťoučký".to_owned()];
+    let fingerprint = Fingerprint::new(&own, &own[1..]);
+    snapshot::save(&path, "key", &own, fingerprint).unwrap();
+
+    let loaded = snapshot::load::<String>(&path, "key").unwrap().unwrap();
+    assert_eq!(loaded.own, own);
+    assert_eq!(loaded.fingerprint_hash, fingerprint.hash);
+    assert_eq!(loaded.out_items, 2);
+
+    assert!(snapshot::load::<String>(&path, "other key").is_err());
+    assert!(snapshot::load::<u8>(&path, "key").is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn missing_or_truncated() {
+    let path = temp_path("truncated");
+    assert!(snapshot::load::<Vec<u8>>(&path, "key").unwrap().is_none());
+
+    let own = vec![vec![1u8, 2, 3]];
+    snapshot::save(&path, "key", &own, Fingerprint::new(&own, &own)).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+    assert!(snapshot::load::<Vec<u8>>(&path, "key").is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// Whether [SwitchableLenSpec] has constant item length (rather than uniform) - as if it were
+/// changed through environment variable `ITEM_LEN_DIST`.
+static CONSTANT_LEN: AtomicBool = AtomicBool::new(false);
+
+struct SwitchableLenSpec;
+impl BenchSpec for SwitchableLenSpec {
+    const NAME: &'static str = "u8slice";
+    type Own = Vec<u8>;
+    type Sub = u8;
+    type OutIndicatorIndicatorImpl = OutIndicatorSliceIndicator;
+    type OutCollectionIndicatorImpl = OutCollectionVecIndicator;
+    type Rnd = BenchRnd;
+
+    fn generate_own_item(rnd: &mut BenchRnd) -> Vec<u8> {
+        rnd.bytes_for_len_dist(Self::item_len_dist())
+    }
+    fn generate_out_item(own: &'static Vec<u8>) -> SpecOut<Self> {
+        &own[..]
+    }
+    fn item_len_dist() -> Distribution {
+        if CONSTANT_LEN.load(Ordering::Relaxed) {
+            Distribution::Constant
+        } else {
+            Distribution::Uniform
+        }
+    }
+}
+
+#[test]
+fn changed_config_misses() {
+    let seed = "RND_SEED_DEC=1";
+    let uniform_key = SwitchableLenSpec::snapshot_key(seed);
+    CONSTANT_LEN.store(true, Ordering::Relaxed);
+    let constant_key = SwitchableLenSpec::snapshot_key(seed);
+    assert_ne!(uniform_key, constant_key);
+    assert_ne!(
+        snapshot::path_for_key(SwitchableLenSpec::NAME, &uniform_key),
+        snapshot::path_for_key(SwitchableLenSpec::NAME, &constant_key)
+    );
+
+    // Even if the paths collided, the stored key wouldn't match.
+    let path = temp_path("changed-config");
+    let own = vec![vec![1u8, 2, 3]];
+    snapshot::save(&path, &uniform_key, &own, Fingerprint::new(&own, &own)).unwrap();
+    assert!(snapshot::load::<Vec<u8>>(&path, &uniform_key)
+        .unwrap()
+        .is_some());
+    assert!(snapshot::load::<Vec<u8>>(&path, &constant_key).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
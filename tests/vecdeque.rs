@@ -0,0 +1,54 @@
+use cami::Cami;
+use cami_benches::col;
+use cami_benches::outish::{OutCollection, OutCollectionVecDeque, OutCollectionVecDequeWrapped};
+use cami_benches::spec::{AllocVecDequeWrappedStr, BenchSpec};
+
+const ITEMS: [&str; 7] = ["d", "bb", "a", "ccc", "a", "e", "b"];
+
+#[test]
+fn contiguous() {
+    let sorted = col::lexi_indicated::<&str, OutCollectionVecDeque<&str>>(&ITEMS, true);
+    assert!(sorted.is_sorted());
+    assert_eq!(sorted.len(), ITEMS.len());
+    assert!(sorted.0.as_slices().1.is_empty());
+    assert!(sorted.binary_search(&"ccc"));
+    assert!(!sorted.binary_search(&"c"));
+}
+
+#[test]
+fn wrapped_around() {
+    let mut col = OutCollectionVecDequeWrapped::<&str>::with_capacity(ITEMS.len());
+    col.extend(ITEMS);
+    assert!(col.is_wrapped());
+    assert!(col.iter().eq(ITEMS.iter()));
+
+    for stable_sort in [true, false] {
+        let sorted =
+            col::lexi_indicated::<&str, OutCollectionVecDequeWrapped<&str>>(&ITEMS, stable_sort);
+        assert!(sorted.is_wrapped());
+        assert!(sorted.is_sorted());
+        for item in ITEMS {
+            assert!(sorted.binary_search(&item));
+        }
+        assert!(!sorted.binary_search(&"c"));
+    }
+}
+
+#[test]
+fn bench_sized_stays_wrapped() {
+    let own_and_out = AllocVecDequeWrappedStr::own_and_out_for_seed(&[1]);
+    let out = own_and_out.out;
+    for stable_sort in [true, false] {
+        let sorted =
+            col::lexi_indicated::<&str, OutCollectionVecDequeWrapped<&str>>(out, stable_sort);
+        assert!(sorted.is_wrapped());
+        assert_eq!(sorted.0.as_slices().0.len(), out.len() / 2);
+        assert!(sorted.is_sorted());
+        assert_eq!(sorted.len(), out.len());
+
+        let sorted =
+            col::cami_indicated::<&str, OutCollectionVecDequeWrapped<Cami<&str>>>(out, stable_sort);
+        assert!(sorted.is_wrapped());
+        assert!(sorted.is_sorted());
+    }
+}
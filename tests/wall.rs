@@ -0,0 +1,32 @@
+#![cfg(feature = "wall")]
+
+use cami_benches::shared_wall::{SeedsStats, Stats};
+
+#[test]
+fn stats_reject_outliers() {
+    let stats = Stats::from_samples(&[10.0, 11.0, 9.0, 10.0, 1000.0], 3.0);
+    assert_eq!(stats.rejected, 1);
+    assert_eq!(stats.kept, 4);
+    assert_eq!(stats.median, 10.0);
+    assert_eq!(stats.mad, 0.5);
+    assert_eq!(stats.min, 9.0);
+    assert_eq!(stats.max, 11.0);
+}
+
+#[test]
+fn stats_zero_mad_rejects_nothing() {
+    let stats = Stats::from_samples(&[5.0, 5.0, 5.0, 100.0], 3.0);
+    assert_eq!(stats.rejected, 0);
+    assert_eq!(stats.median, 5.0);
+    assert_eq!(stats.max, 100.0);
+}
+
+#[test]
+fn seeds_stats_outlier() {
+    let stats = SeedsStats::from_medians(&[10.0, 12.0, 8.0, 30.0]);
+    assert_eq!(stats.mean, 15.0);
+    assert_eq!(stats.min, 8.0);
+    assert_eq!(stats.max, 30.0);
+    assert_eq!(stats.outlier, 3);
+    assert!((stats.stddev - 8.774_964).abs() < 1e-6);
+}